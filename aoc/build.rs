@@ -0,0 +1,49 @@
+use std::{env, fs, path::Path};
+
+// writes a test for every example input in fixtures/, they are included by tests/fixtures.rs
+fn main() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+    println!("cargo:rerun-if-changed={}", fixtures.display());
+
+    let mut tests = String::new();
+
+    for day in sorted_entries(&fixtures) {
+        let day_name = file_name(&day);
+
+        for file in sorted_entries(&day) {
+            let file_name = file_name(&file);
+
+            // example.txt is an input, example.part1.txt an expected answer
+            let example = match file_name.strip_suffix(".txt") {
+                Some(example) if !example.contains('.') => example,
+                _ => continue,
+            };
+
+            let test_name = format!("{}_{}", day_name, example)
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+            tests.push_str(&format!(
+                "#[test]\nfn {}() {{\n    check({:?}, {:?});\n}}\n\n",
+                test_name, day_name, example
+            ));
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("fixture_tests.rs"), tests).unwrap();
+}
+
+fn sorted_entries(directory: &Path) -> Vec<std::path::PathBuf> {
+    let mut entries = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+
+    entries.sort();
+
+    entries
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().unwrap().to_string_lossy().into_owned()
+}
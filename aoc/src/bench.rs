@@ -0,0 +1,287 @@
+use std::{
+    collections::BTreeMap,
+    hint::black_box,
+    iter::Peekable,
+    str::Chars,
+    time::{Duration, Instant},
+};
+
+use common::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    const PHASES: [&'static str; 3] = ["parse", "part1", "part2"];
+
+    fn phases(&self) -> [Duration; 3] {
+        [self.parse, self.part1, self.part2]
+    }
+}
+
+// the saved timings of the last run, by day
+pub type Baseline = BTreeMap<u32, Timings>;
+
+// median of every phase over `runs` runs, the input is parsed again for every run
+pub fn measure<S: Solution>(input_string: &str, runs: usize) -> Result<Timings, ParseError> {
+    let mut parse = Vec::new();
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let input = S::parse(black_box(input_string))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::try_part1(&input)?);
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::try_part2(&input)?);
+        part2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        parse: median(parse),
+        part1: median(part1),
+        part2: median(part2),
+    })
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+
+    durations[durations.len() / 2]
+}
+
+// phases that got slower by more than `threshold` percent, with how much slower they got
+pub fn regressions(
+    previous: &Timings,
+    current: &Timings,
+    threshold: f64,
+) -> Vec<(&'static str, f64)> {
+    Timings::PHASES
+        .iter()
+        .zip(previous.phases().iter().zip(current.phases()))
+        .filter(|(_, (previous, _))| !previous.is_zero())
+        .map(|(phase, (previous, current))| {
+            let change = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+
+            (*phase, change)
+        })
+        .filter(|(_, change)| *change > threshold)
+        .collect()
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+// {"7": {"parse": 1200, "part1": 300, "part2": 400}, ...} with the timings in nanoseconds
+pub fn to_json(baseline: &Baseline) -> String {
+    let days = baseline
+        .iter()
+        .map(|(day, timings)| {
+            let phases = Timings::PHASES
+                .iter()
+                .zip(timings.phases())
+                .map(|(phase, duration)| format!("\"{}\": {}", phase, duration.as_nanos()))
+                .collect::<Vec<_>>();
+
+            format!("  \"{}\": {{{}}}", day, phases.join(", "))
+        })
+        .collect::<Vec<_>>();
+
+    format!("{{\n{}\n}}\n", days.join(",\n"))
+}
+
+// only reads what to_json writes
+pub fn from_json(text: &str) -> Result<Baseline, String> {
+    let mut reader = JsonReader {
+        chars: text.chars().peekable(),
+    };
+
+    let mut baseline = Baseline::new();
+
+    reader.object(|reader, day| {
+        let day = day
+            .parse()
+            .map_err(|_| format!("invalid day in baseline: {}", day))?;
+
+        let mut phases = BTreeMap::new();
+        reader.object(|reader, phase| {
+            phases.insert(phase, Duration::from_nanos(reader.number()?));
+            Ok(())
+        })?;
+
+        let phase = |name: &str| {
+            phases
+                .get(name)
+                .copied()
+                .ok_or_else(|| format!("missing {} for day {} in baseline", name, day))
+        };
+
+        baseline.insert(
+            day,
+            Timings {
+                parse: phase("parse")?,
+                part1: phase("part1")?,
+                part2: phase("part2")?,
+            },
+        );
+
+        Ok(())
+    })?;
+
+    Ok(baseline)
+}
+
+struct JsonReader<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl JsonReader<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!(
+                "expected '{}' in baseline, found '{}'",
+                expected, c
+            )),
+            None => Err(format!(
+                "expected '{}' in baseline, found the end",
+                expected
+            )),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+
+        let mut string = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(string),
+                Some(c) => string.push(c),
+                None => return Err("unterminated string in baseline".to_owned()),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<u64, String> {
+        self.skip_whitespace();
+
+        let mut digits = String::new();
+        while let Some(digit) = self.chars.next_if(char::is_ascii_digit) {
+            digits.push(digit);
+        }
+
+        digits
+            .parse()
+            .map_err(|_| "expected a number in baseline".to_owned())
+    }
+
+    // calls `value` for every key, which has to read the value itself
+    fn object<F>(&mut self, mut value: F) -> Result<(), String>
+    where
+        F: FnMut(&mut Self, String) -> Result<(), String>,
+    {
+        self.expect('{')?;
+        self.skip_whitespace();
+
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(());
+        }
+
+        loop {
+            let key = self.string()?;
+            self.expect(':')?;
+            value(self, key)?;
+
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(()),
+                _ => return Err("expected ',' or '}' in baseline".to_owned()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{format_duration, from_json, regressions, to_json, Baseline, Timings};
+
+    fn timings(parse: u64, part1: u64, part2: u64) -> Timings {
+        Timings {
+            parse: Duration::from_nanos(parse),
+            part1: Duration::from_nanos(part1),
+            part2: Duration::from_nanos(part2),
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let mut baseline = Baseline::new();
+        baseline.insert(7, timings(1200, 300, 400));
+        baseline.insert(12, timings(5, 2_000_000, 0));
+
+        let json = to_json(&baseline);
+        assert_eq!(
+            json,
+            "{\n  \"7\": {\"parse\": 1200, \"part1\": 300, \"part2\": 400},\n  \"12\": {\"parse\": 5, \"part1\": 2000000, \"part2\": 0}\n}\n"
+        );
+        assert_eq!(from_json(&json), Ok(baseline));
+
+        assert_eq!(from_json("{}"), Ok(Baseline::new()));
+        assert_eq!(
+            from_json("{\"7\": {\"parse\": 1, \"part1\": 2}}"),
+            Err("missing part2 for day 7 in baseline".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_regressions() {
+        let previous = timings(1000, 1000, 0);
+
+        assert_eq!(
+            regressions(&previous, &timings(1050, 900, 500), 10.0),
+            vec![]
+        );
+        assert_eq!(
+            regressions(&previous, &timings(1050, 1500, 500), 10.0),
+            vec![("part1", 50.0)]
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.5µs");
+        assert_eq!(format_duration(Duration::from_millis(12)), "12.0ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+}
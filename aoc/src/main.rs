@@ -0,0 +1,260 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+use aoc::{
+    bench::{self, Baseline},
+    registry::{self, Day},
+    scaffold,
+};
+use common::input::{day_file_name, Source};
+
+// without --input the day is read from the cache, e.g. ~/.cache/aoc/2022/day07.txt
+const USAGE: &str = "usage:
+  aoc run --day <N> [--part <1|2>] [--input <path>|-]
+  aoc bench [--day <N>] [--input <path>|-] [--runs <N>] [--baseline <path>] [--threshold <percent>]
+  aoc new --day <N>";
+
+const DEFAULT_BASELINE: &str = "target/aoc-bench.json";
+
+struct RunArgs {
+    day: u32,
+    part: Option<u32>,
+    input: Option<String>,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--day" => {
+                day = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid day: {}", value))?,
+                )
+            }
+            "--part" => match value.as_str() {
+                "1" => part = Some(1),
+                "2" => part = Some(2),
+                _ => return Err(format!("invalid part: {}", value)),
+            },
+            "--input" => input = Some(value.clone()),
+            _ => return Err(format!("unknown argument: {}", flag)),
+        }
+    }
+
+    Ok(RunArgs {
+        day: day.ok_or("missing --day")?,
+        part,
+        input,
+    })
+}
+
+fn find_day(number: u32) -> Result<&'static Day, String> {
+    registry::find(number).ok_or_else(|| format!("no solution for day {}", number))
+}
+
+fn read_input(day: &Day, input: Option<&str>) -> Result<String, String> {
+    Source::from_arg(input, &day_file_name(day.number))
+        .read()
+        .map_err(|error| format!("could not read input for day {}: {}", day.number, error))
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let day = find_day(args.day)?;
+    let input = read_input(day, args.input.as_deref())?;
+
+    let parts = match args.part {
+        Some(1) => vec![(1, day.part1)],
+        Some(_) => vec![(2, day.part2)],
+        None => vec![(1, day.part1), (2, day.part2)],
+    };
+
+    for (number, solve) in parts {
+        let answer = solve(&input).map_err(|error| format!("day{} {}", day.number, error))?;
+
+        println!("Day {} part {}: {}", day.number, number, answer);
+    }
+
+    Ok(())
+}
+
+struct BenchArgs {
+    day: Option<u32>,
+    input: Option<String>,
+    runs: usize,
+    baseline: PathBuf,
+    threshold: f64,
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {}: {}", flag, value))
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        input: None,
+        runs: 10,
+        baseline: PathBuf::from(DEFAULT_BASELINE),
+        threshold: 10.0,
+    };
+
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--day" => bench_args.day = Some(parse_value(flag, value)?),
+            "--input" => bench_args.input = Some(value.clone()),
+            "--runs" => bench_args.runs = parse_value(flag, value)?,
+            "--baseline" => bench_args.baseline = PathBuf::from(value),
+            "--threshold" => bench_args.threshold = parse_value(flag, value)?,
+            _ => return Err(format!("unknown argument: {}", flag)),
+        }
+    }
+
+    if bench_args.input.is_some() && bench_args.day.is_none() {
+        return Err("--input needs --day".to_owned());
+    }
+
+    Ok(bench_args)
+}
+
+fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => {
+            bench::from_json(&text).map_err(|error| format!("{}: {}", path.display(), error))
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(error) => Err(format!("{}: {}", path.display(), error)),
+    }
+}
+
+fn write_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, bench::to_json(baseline))
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let days = match args.day {
+        Some(number) => vec![find_day(number)?],
+        None => registry::DAYS.iter().collect(),
+    };
+
+    let previous = read_baseline(&args.baseline)?;
+    // days that aren't benchmarked this time keep their old timings
+    let mut current = previous.clone();
+    let mut regression_count = 0;
+
+    println!(
+        "{:>3}  {:>10}  {:>10}  {:>10}",
+        "day", "parse", "part1", "part2"
+    );
+
+    for day in days {
+        let input = match read_input(day, args.input.as_deref()) {
+            Ok(input) => input,
+            // benching every day shouldn't stop at the first missing input
+            Err(message) if args.day.is_none() => {
+                eprintln!("skipping day {}, {}", day.number, message);
+                continue;
+            }
+            Err(message) => return Err(message),
+        };
+
+        let timings = (day.bench)(&input, args.runs)
+            .map_err(|error| format!("day{} {}", day.number, error))?;
+
+        let regressions = previous
+            .get(&day.number)
+            .map(|previous| bench::regressions(previous, &timings, args.threshold))
+            .unwrap_or_default();
+
+        let flags = regressions
+            .iter()
+            .map(|(phase, change)| format!("  {} {:+.0}%", phase, change))
+            .collect::<String>();
+
+        println!(
+            "{:>3}  {:>10}  {:>10}  {:>10}{}",
+            day.number,
+            bench::format_duration(timings.parse),
+            bench::format_duration(timings.part1),
+            bench::format_duration(timings.part2),
+            flags
+        );
+
+        regression_count += regressions.len();
+        current.insert(day.number, timings);
+    }
+
+    if regression_count > 0 {
+        println!(
+            "{} phase(s) more than {}% slower than {}",
+            regression_count,
+            args.threshold,
+            args.baseline.display()
+        );
+    }
+
+    write_baseline(&args.baseline, &current)
+        .map_err(|error| format!("could not save {}: {}", args.baseline.display(), error))
+}
+
+fn parse_new_args(args: &[String]) -> Result<u32, String> {
+    match args {
+        [flag, value] if flag == "--day" => parse_value(flag, value),
+        _ => Err(USAGE.to_owned()),
+    }
+}
+
+fn new(number: u32) -> Result<(), String> {
+    let root = scaffold::workspace_root()?;
+
+    for path in scaffold::new_day(&root, number)? {
+        println!(
+            "wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).and_then(run),
+        Some("bench") => parse_bench_args(&args[1..]).and_then(bench),
+        Some("new") => parse_new_args(&args[1..]).and_then(new),
+        _ => Err(USAGE.to_owned()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
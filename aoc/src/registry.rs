@@ -0,0 +1,55 @@
+use common::{Answer, ParseError, Solution};
+
+use crate::bench::{self, Timings};
+
+pub type Part = fn(&str) -> Result<Answer, ParseError>;
+
+pub struct Day {
+    pub number: u32,
+    pub part1: Part,
+    pub part2: Part,
+    // times the phases over the given number of runs
+    pub bench: fn(&str, usize) -> Result<Timings, ParseError>,
+}
+
+fn part1<S: Solution>(input_string: &str) -> Result<Answer, ParseError> {
+    S::try_part1(&S::parse(input_string)?)
+}
+
+fn part2<S: Solution>(input_string: &str) -> Result<Answer, ParseError> {
+    S::try_part2(&S::parse(input_string)?)
+}
+
+const fn day<S: Solution>(number: u32) -> Day {
+    Day {
+        number,
+        part1: part1::<S>,
+        part2: part2::<S>,
+        bench: bench::measure::<S>,
+    }
+}
+
+// day1knowit has a Solution too, but it isn't an advent of code day so there is no number to
+// run it by, and its binary takes the dictionary and the letter as two files
+pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(1),
+    day::<day2::Day2>(2),
+    day::<day3::Day3>(3),
+    day::<day4::Day4>(4),
+    day::<day5::Day5>(5),
+    day::<day6::Day6>(6),
+    day::<day7::Day7>(7),
+    day::<day8::Day8>(8),
+    day::<day9::Day9>(9),
+    day::<day10::Day10>(10),
+    day::<day11::Day11>(11),
+    day::<day12::Day12>(12),
+    day::<day13::Day13>(13),
+    day::<day14::Day14>(14),
+    day::<day15::Day15>(15),
+    day::<day16::Day16>(16),
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,196 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE_MANIFEST: &str = include_str!("../../template/Cargo.toml");
+const TEMPLATE_GITIGNORE: &str = include_str!("../../template/.gitignore");
+const TEMPLATE_MAIN: &str = include_str!("../../template/src/main.rs");
+const TEMPLATE_LIB: &str = include_str!("../../template/src/lib.rs");
+
+// the nearest directory above the current one with a [workspace] manifest
+pub fn workspace_root() -> Result<PathBuf, String> {
+    let current_dir = env::current_dir().map_err(|error| error.to_string())?;
+
+    current_dir
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| "not inside the workspace".to_owned())
+}
+
+// copies template into a new crate, registers it in the workspace and the runner and adds
+// empty fixtures, returns the files that were written
+pub fn new_day(root: &Path, number: u32) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{}", number);
+    let crate_dir = root.join(&name);
+    let fixture_dir = root.join("fixtures").join(fixture_name(number));
+
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    let read = |path: &str| {
+        fs::read_to_string(root.join(path)).map_err(|error| format!("{}: {}", path, error))
+    };
+
+    // everything is worked out before writing so a failure doesn't leave half a day behind
+    let files = vec![
+        (crate_dir.join("Cargo.toml"), manifest(number)?),
+        (crate_dir.join(".gitignore"), TEMPLATE_GITIGNORE.to_owned()),
+        (crate_dir.join("src/main.rs"), main_source(number)?),
+        (crate_dir.join("src/lib.rs"), lib_source(number)?),
+        (
+            root.join("Cargo.toml"),
+            insert_day_line(&read("Cargo.toml")?, number, |n| {
+                format!("    \"day{}\",", n)
+            })?,
+        ),
+        (
+            root.join("aoc/Cargo.toml"),
+            insert_day_line(&read("aoc/Cargo.toml")?, number, |n| {
+                format!("day{0} = {{ path = \"../day{0}\" }}", n)
+            })?,
+        ),
+        (
+            root.join("aoc/src/registry.rs"),
+            insert_day_line(&read("aoc/src/registry.rs")?, number, |n| {
+                format!("    day::<day{0}::Day{0}>({0}),", n)
+            })?,
+        ),
+        (fixture_dir.join("example.txt"), String::new()),
+        (fixture_dir.join("example.part1.txt"), String::new()),
+        (fixture_dir.join("example.part2.txt"), String::new()),
+    ];
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("{}: {}", parent.display(), error))?;
+        }
+
+        fs::write(path, contents).map_err(|error| format!("{}: {}", path.display(), error))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn fixture_name(number: u32) -> String {
+    format!("day{:02}", number)
+}
+
+fn replace_once(source: &str, file: &str, from: &str, to: &str) -> Result<String, String> {
+    if source.matches(from).count() != 1 {
+        return Err(format!("expected exactly one '{}' in {}", from, file));
+    }
+
+    Ok(source.replace(from, to))
+}
+
+fn manifest(number: u32) -> Result<String, String> {
+    replace_once(
+        TEMPLATE_MANIFEST,
+        "template/Cargo.toml",
+        "name = \"template\"",
+        &format!("name = \"day{}\"", number),
+    )
+}
+
+fn main_source(number: u32) -> Result<String, String> {
+    let file = "template/src/main.rs";
+    let source = replace_once(
+        TEMPLATE_MAIN,
+        file,
+        "use template::",
+        &format!("use day{}::", number),
+    )?;
+
+    replace_once(
+        &source,
+        file,
+        "input::from_args(0)",
+        &format!("input::from_args({})", number),
+    )
+}
+
+fn lib_source(number: u32) -> Result<String, String> {
+    let file = "template/src/lib.rs";
+    let source = TEMPLATE_LIB.replace("Template", &format!("Day{}", number));
+
+    replace_once(
+        &source,
+        file,
+        "const TEST_INPUT: &str = \"\";",
+        &format!(
+            "const TEST_INPUT: &str = include_str!(\"../../fixtures/{}/example.txt\");",
+            fixture_name(number)
+        ),
+    )
+}
+
+// adds the line for `number` after the line of the closest earlier day, keeping the days in order
+fn insert_day_line<F>(text: &str, number: u32, line_for: F) -> Result<String, String>
+where
+    F: Fn(u32) -> String,
+{
+    let mut lines = text.lines().map(str::to_owned).collect::<Vec<_>>();
+    let line = line_for(number);
+
+    if lines.contains(&line) {
+        return Err(format!(
+            "day {} is already registered: {}",
+            number,
+            line.trim()
+        ));
+    }
+
+    let previous = (1..number)
+        .filter_map(|earlier| lines.iter().position(|line| *line == line_for(earlier)))
+        .max()
+        .ok_or_else(|| format!("no earlier day to put day {} after", number))?;
+
+    lines.insert(previous + 1, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{insert_day_line, lib_source, main_source, manifest};
+
+    #[test]
+    fn test_insert_day_line() {
+        let members = "members = [\n    \"day1\",\n    \"day1knowit\",\n    \"day2\",\n    \"day16\",\n    \"template\",\n]\n";
+        let line_for = |n| format!("    \"day{}\",", n);
+
+        assert_eq!(
+            insert_day_line(members, 17, line_for),
+            Ok("members = [\n    \"day1\",\n    \"day1knowit\",\n    \"day2\",\n    \"day16\",\n    \"day17\",\n    \"template\",\n]\n".to_owned())
+        );
+        assert_eq!(
+            insert_day_line(members, 3, line_for),
+            Ok("members = [\n    \"day1\",\n    \"day1knowit\",\n    \"day2\",\n    \"day3\",\n    \"day16\",\n    \"template\",\n]\n".to_owned())
+        );
+        assert_eq!(
+            insert_day_line(members, 16, line_for),
+            Err("day 16 is already registered: \"day16\",".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_template() {
+        assert!(manifest(17).unwrap().contains("name = \"day17\""));
+
+        let main = main_source(17).unwrap();
+        assert!(main.contains("use day17::{part1, part2};"));
+        assert!(main.contains("input::from_args(17)"));
+
+        let lib = lib_source(17).unwrap();
+        assert!(lib.contains("pub struct Day17;"));
+        assert!(lib.contains("impl Solution for Day17 {"));
+        assert!(lib.contains("include_str!(\"../../fixtures/day17/example.txt\")"));
+    }
+}
@@ -0,0 +1,39 @@
+use std::{fs, path::Path};
+
+use aoc::registry;
+
+// runs both parts on fixtures/<day>/<example>.txt and compares them with
+// <example>.part1.txt and <example>.part2.txt, both have to exist but can be left empty
+fn check(day: &str, example: &str) {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../fixtures")
+        .join(day);
+
+    let number = day.trim_start_matches("day").parse().unwrap();
+    let solution =
+        registry::find(number).unwrap_or_else(|| panic!("no solution registered for {}", day));
+
+    let input = fs::read_to_string(directory.join(format!("{}.txt", example))).unwrap();
+
+    for (part, solve) in [(1, solution.part1), (2, solution.part2)] {
+        let answer = solve(&input).unwrap_or_else(|error| panic!("{}/{}: {}", day, example, error));
+
+        // a missing answer file is a mistake, an empty one means the answer isn't known yet
+        let answer_file = directory.join(format!("{}.part{}.txt", example, part));
+        let expected = fs::read_to_string(&answer_file)
+            .unwrap_or_else(|error| panic!("{}: {}", answer_file.display(), error));
+
+        if !expected.trim().is_empty() {
+            assert_eq!(
+                answer.to_string().trim(),
+                expected.trim(),
+                "{}/{} part {}",
+                day,
+                example,
+                part
+            );
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
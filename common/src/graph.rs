@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+pub type NodeId = usize;
+
+// nodes are interned to ids 0..len in the order they are first seen
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    // outgoing edges with their weights, by node id
+    edges: Vec<Vec<(NodeId, u32)>>,
+}
+
+impl<N: Eq + Hash + Clone> Graph<N> {
+    pub fn new() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub fn intern(&mut self, node: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+
+        let id = self.nodes.len();
+        self.ids.insert(node.clone(), id);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+
+        id
+    }
+
+    pub fn id(&self, node: &N) -> Option<NodeId> {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    // directed, add the edge both ways for an undirected graph
+    pub fn add_edge(&mut self, from: N, to: N, weight: u32) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, u32)] {
+        &self.edges[id]
+    }
+
+    pub fn floyd_warshall(&self) -> Distances {
+        let mut distances = Distances::new(self.len());
+
+        for (from, edges) in self.edges.iter().enumerate() {
+            distances.set(from, from, 0);
+
+            for &(to, weight) in edges {
+                if distances
+                    .get(from, to)
+                    .is_none_or(|distance| weight < distance)
+                {
+                    distances.set(from, to, weight);
+                }
+            }
+        }
+
+        for k in 0..self.len() {
+            for i in 0..self.len() {
+                let Some(ik) = distances.get(i, k) else {
+                    continue;
+                };
+
+                for j in 0..self.len() {
+                    let Some(kj) = distances.get(k, j) else {
+                        continue;
+                    };
+
+                    if distances.get(i, j).is_none_or(|ij| ik + kj < ij) {
+                        distances.set(i, j, ik + kj);
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    // a breadth first search from every node, which counts edges and ignores the weights
+    pub fn bfs_distances(&self) -> Distances {
+        let mut distances = Distances::new(self.len());
+
+        for from in 0..self.len() {
+            let mut queue = VecDeque::from([from]);
+            distances.set(from, from, 0);
+
+            while let Some(id) = queue.pop_front() {
+                let distance = distances.get(from, id).unwrap();
+
+                for &(to, _) in &self.edges[id] {
+                    if distances.get(from, to).is_none() {
+                        distances.set(from, to, distance + 1);
+                        queue.push_back(to);
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    // keeps the nodes passing `keep`, joining every pair that can reach each other with an
+    // edge as long as the distance between them
+    pub fn compress<F>(&self, distances: &Distances, mut keep: F) -> Graph<N>
+    where
+        F: FnMut(&N) -> bool,
+    {
+        let kept = (0..self.len())
+            .filter(|&id| keep(&self.nodes[id]))
+            .collect::<Vec<_>>();
+
+        let mut graph = Graph::new();
+
+        for &id in &kept {
+            graph.intern(self.nodes[id].clone());
+        }
+
+        for &from in &kept {
+            for &to in &kept {
+                if let Some(distance) = distances.get(from, to).filter(|_| from != to) {
+                    graph.add_edge(self.nodes[from].clone(), self.nodes[to].clone(), distance);
+                }
+            }
+        }
+
+        graph
+    }
+}
+
+impl<N: Eq + Hash + Clone> Default for Graph<N> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+// a dense matrix of the distances between every pair of node ids
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances {
+    size: usize,
+    matrix: Vec<Option<u32>>,
+}
+
+impl Distances {
+    fn new(size: usize) -> Self {
+        Distances {
+            size,
+            matrix: vec![None; size * size],
+        }
+    }
+
+    fn set(&mut self, from: NodeId, to: NodeId, distance: u32) {
+        self.matrix[from * self.size + to] = Some(distance);
+    }
+
+    // None if `to` can't be reached from `from`
+    pub fn get(&self, from: NodeId, to: NodeId) -> Option<u32> {
+        self.matrix[from * self.size + to]
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+
+    // a - b - c - d in a line, plus e that nothing leads to
+    fn line() -> Graph<char> {
+        let mut graph = Graph::new();
+
+        for (from, to) in [('a', 'b'), ('b', 'c'), ('c', 'd')] {
+            graph.add_edge(from, to, 1);
+            graph.add_edge(to, from, 1);
+        }
+
+        graph.add_edge('e', 'a', 1);
+
+        graph
+    }
+
+    #[test]
+    fn test_intern() {
+        let mut graph = line();
+
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.id(&'c'), Some(2));
+        assert_eq!(graph.intern('c'), 2);
+        assert_eq!(graph.node(4), &'e');
+        assert_eq!(graph.edges(1), &[(0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let graph = line();
+        let distances = graph.floyd_warshall();
+
+        assert_eq!(distances.get(0, 3), Some(3));
+        assert_eq!(distances.get(3, 0), Some(3));
+        assert_eq!(distances.get(2, 2), Some(0));
+        assert_eq!(distances.get(4, 3), Some(4));
+        assert_eq!(distances.get(3, 4), None);
+
+        assert_eq!(graph.bfs_distances(), distances);
+    }
+
+    #[test]
+    fn test_weights() {
+        let mut graph = line();
+        // a shortcut that is longer than going the long way round
+        graph.add_edge('a', 'd', 5);
+
+        assert_eq!(graph.floyd_warshall().get(0, 3), Some(3));
+        assert_eq!(graph.bfs_distances().get(0, 3), Some(1));
+    }
+
+    #[test]
+    fn test_compress() {
+        let graph = line();
+        let compressed = graph.compress(&graph.floyd_warshall(), |&node| node != 'b');
+
+        assert_eq!(compressed.nodes(), &['a', 'c', 'd', 'e']);
+        assert_eq!(compressed.edges(0), &[(1, 2), (2, 3)]);
+        assert_eq!(compressed.edges(3), &[(0, 1), (1, 3), (2, 4)]);
+        assert_eq!(compressed.floyd_warshall().get(3, 2), Some(4));
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    fmt, iter,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+// (x, y) with y growing downwards
+pub type Point = (usize, usize);
+pub type Direction = (isize, isize);
+
+pub const NEIGHBOURS4: [Direction; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const NEIGHBOURS8: [Direction; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// cells are stored row by row in one vec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // panics unless the cells fill whole rows
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells don't fill rows of {}",
+            cells.len(),
+            width
+        );
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // one cell per character, `cell` returns None for characters that don't belong in the map
+    pub fn parse<F>(input_string: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for (index, line) in input_string.lines().enumerate() {
+            for (offset, character) in line.char_indices() {
+                let text = &line[offset..offset + character.len_utf8()];
+
+                cells.push(cell(character).ok_or_else(|| {
+                    ParseError::new(line, text, "unexpected character").on_line(index + 1)
+                })?);
+            }
+
+            let line_width = line.chars().count();
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    let message = format!("expected {} cells, found {}", width, line_width);
+
+                    return Err(ParseError::new(line, line, &message).on_line(index + 1));
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Grid::from_cells(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        Some(&mut self.cells[point.1 * self.width + point.0])
+    }
+
+    // the next point in `direction`, if it is inside the grid
+    pub fn step(&self, (x, y): Point, (dx, dy): Direction) -> Option<Point> {
+        let point = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(point).then_some(point)
+    }
+
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS4
+            .iter()
+            .filter_map(move |&direction| self.step(point, direction))
+    }
+
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .filter_map(move |&direction| self.step(point, direction))
+    }
+
+    // the points from `point` towards the edge, not including `point` itself
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        iter::successors(self.step(point, direction), move |&point| {
+            self.step(point, direction)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} outside width {}", x, self.width);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn position<P>(&self, predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let index = self.cells.iter().position(predicate)?;
+
+        Some((index % self.width, index / self.width))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // rows become columns without copying anything
+    pub fn transposed(&self) -> Transposed<'_, T> {
+        Transposed { grid: self }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        let (width, height) = (self.width, self.height);
+
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid of {}x{}", point, width, height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid of {}x{}", point, width, height))
+    }
+}
+
+fn write_rows<'a, T, R, I>(f: &mut fmt::Formatter<'_>, rows: R) -> fmt::Result
+where
+    T: fmt::Display + 'a,
+    R: Iterator<Item = I>,
+    I: IntoIterator<Item = &'a T>,
+{
+    for (index, row) in rows.enumerate() {
+        if index > 0 {
+            writeln!(f)?;
+        }
+
+        for cell in row {
+            write!(f, "{}", cell)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+#[derive(Debug)]
+pub struct Transposed<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Transposed<'a, T> {
+    pub fn width(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.width()
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&'a T> {
+        self.grid.get((y, x))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &'a T> {
+        self.grid.column(y)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        self.grid.columns()
+    }
+
+    pub fn column(&self, x: usize) -> &'a [T] {
+        self.grid.row(x)
+    }
+
+    pub fn transposed(&self) -> &'a Grid<T> {
+        self.grid
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Transposed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, NEIGHBOURS4};
+
+    const MAP: &str = "abc\ndef";
+
+    fn letters() -> Grid<char> {
+        Grid::parse(MAP, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = letters();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), MAP);
+
+        let error = Grid::parse("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected character 'x'"
+        );
+
+        let error = Grid::parse("12\n345", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 2 cells, found 3 '345'"
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = letters();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = letters();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(
+            grid.ray((0, 0), (1, 0))
+                .map(|point| grid[point])
+                .collect::<String>(),
+            "bc"
+        );
+        assert_eq!(
+            grid.ray((1, 1), NEIGHBOURS4[0]).collect::<Vec<_>>(),
+            vec![(1, 0)]
+        );
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_transposed() {
+        let grid = letters();
+        let transposed = grid.transposed();
+
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.get((1, 2)), Some(&'f'));
+        assert_eq!(transposed.column(0), &['a', 'b', 'c']);
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf");
+        assert_eq!(transposed.transposed(), &grid);
+    }
+
+    #[test]
+    fn test_map() {
+        let mut grid = letters().map(|&c| c == 'e');
+        grid[(0, 0)] = true;
+
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, &cell)| cell)
+                .map(|(point, _)| point)
+                .collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+        assert_eq!(Grid::new(2, 2, '.').to_string(), "..\n..");
+    }
+}
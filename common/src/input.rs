@@ -0,0 +1,165 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    // a file name inside the cache directory
+    Cache(String),
+}
+
+impl Source {
+    // "-" means stdin, no argument means the cached input
+    pub fn from_arg(arg: Option<&str>, cache_name: &str) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => Source::Cache(cache_name.to_owned()),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut input_string = String::new();
+                io::stdin().read_to_string(&mut input_string)?;
+
+                Ok(input_string)
+            }
+            Source::Cache(name) => read_file(&cache_dir()?.join(name)),
+        }
+    }
+
+    // for inputs too big to read into memory at once
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::File(path) => open_file(path),
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Cache(name) => open_file(&cache_dir()?.join(name)),
+        }
+    }
+}
+
+fn with_path(path: &Path, error: io::Error) -> io::Error {
+    io::Error::new(error.kind(), format!("{}: {}", path.display(), error))
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|error| with_path(path, error))
+}
+
+fn open_file(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    let file = fs::File::open(path).map_err(|error| with_path(path, error))?;
+
+    Ok(Box::new(BufReader::new(file)))
+}
+
+pub fn day_file_name(day: u32) -> String {
+    format!("day{:02}.txt", day)
+}
+
+// $AOC_CACHE_DIR if set, otherwise aoc/2022 inside the user's cache directory
+pub fn cache_dir() -> io::Result<PathBuf> {
+    cache_dir_from(
+        env::var_os("AOC_CACHE_DIR").map(PathBuf::from),
+        env::var_os("XDG_CACHE_HOME").map(PathBuf::from),
+        env::var_os("HOME").map(PathBuf::from),
+    )
+    .ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "no cache directory, set AOC_CACHE_DIR or HOME",
+        )
+    })
+}
+
+fn cache_dir_from(
+    override_dir: Option<PathBuf>,
+    xdg_cache_home: Option<PathBuf>,
+    home: Option<PathBuf>,
+) -> Option<PathBuf> {
+    if override_dir.is_some() {
+        return override_dir;
+    }
+
+    let cache_home = xdg_cache_home.or_else(|| home.map(|home| home.join(".cache")))?;
+
+    Some(cache_home.join("aoc").join("2022"))
+}
+
+// reads the input of a day binary from its first command line argument
+pub fn from_args(day: u32) -> io::Result<String> {
+    Source::from_arg(env::args().nth(1).as_deref(), &day_file_name(day)).read()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::BufRead, path::PathBuf};
+
+    use crate::input::{cache_dir_from, day_file_name, Source};
+
+    #[test]
+    fn test_day_file_name() {
+        assert_eq!(day_file_name(7), "day07.txt");
+        assert_eq!(day_file_name(16), "day16.txt");
+    }
+
+    #[test]
+    fn test_cache_dir() {
+        let home = Some(PathBuf::from("/home/elf"));
+
+        assert_eq!(
+            cache_dir_from(None, None, home.clone()),
+            Some(PathBuf::from("/home/elf/.cache/aoc/2022"))
+        );
+        assert_eq!(
+            cache_dir_from(None, Some(PathBuf::from("/tmp/cache")), home.clone()),
+            Some(PathBuf::from("/tmp/cache/aoc/2022"))
+        );
+        assert_eq!(
+            cache_dir_from(Some(PathBuf::from("/srv/inputs")), None, home),
+            Some(PathBuf::from("/srv/inputs"))
+        );
+        assert_eq!(cache_dir_from(None, None, None), None);
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg(Some("-"), "day01.txt"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("input.txt"), "day01.txt"),
+            Source::File(PathBuf::from("input.txt"))
+        );
+        assert_eq!(
+            Source::from_arg(None, "day01.txt"),
+            Source::Cache("day01.txt".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_read_file() {
+        let path = env::temp_dir().join("common-input-test-read-file.txt");
+        fs::write(&path, "1000\n2000\n").unwrap();
+
+        assert_eq!(Source::File(path.clone()).read().unwrap(), "1000\n2000\n");
+
+        let lines = Source::File(path.clone()).open().unwrap().lines();
+        assert_eq!(
+            lines.map(Result::unwrap).collect::<Vec<_>>(),
+            vec!["1000", "2000"]
+        );
+
+        fs::remove_file(&path).unwrap();
+
+        let error = Source::File(path.clone()).read().unwrap_err();
+        assert!(error.to_string().starts_with(&path.display().to_string()));
+
+        let error = Source::File(path.clone()).open().err().unwrap();
+        assert!(error.to_string().starts_with(&path.display().to_string()));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod search;
+
+pub use parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    // rendered as lines of '#' and '.', e.g. the day10 CRT
+    Bitmap(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            // start on a fresh line so the rows line up
+            Answer::Bitmap(rows) => write!(f, "\n{}", rows.trim_start_matches('\n')),
+            Answer::Unsolved => write!(f, "not solved"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Integer(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    // for parts that can only tell the input is unusable once they get to it, the runner
+    // reports these errors where part1 and part2 can only give up
+    fn try_part1(input: &Self::Input) -> Result<Answer, ParseError> {
+        Ok(Self::part1(input))
+    }
+
+    fn try_part2(input: &Self::Input) -> Result<Answer, ParseError> {
+        Ok(Self::part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42).to_string(), "42");
+        assert_eq!(Answer::from("CMZ".to_owned()).to_string(), "CMZ");
+        assert_eq!(
+            Answer::Bitmap("\n#.\n.#".to_owned()).to_string(),
+            "\n#.\n.#"
+        );
+        assert_eq!(Answer::Unsolved.to_string(), "not solved");
+    }
+}
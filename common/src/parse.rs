@@ -0,0 +1,130 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // 1-indexed, 0 until the error has been placed on a line
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    // `text` should be a slice of `line` so the column can be worked out from it,
+    // anything else is reported at the start of the line
+    pub fn new(line: &str, text: &str, message: &str) -> Self {
+        let offset = (text.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let inside_line = offset
+            .checked_add(text.len())
+            .is_some_and(|end| end <= line.len());
+
+        ParseError {
+            line: 0,
+            column: if inside_line { offset + 1 } else { 1 },
+            text: text.to_owned(),
+            message: message.to_owned(),
+        }
+    }
+
+    pub fn on_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, " '{}'", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse_number<T: FromStr>(line: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(line, text, "invalid number"))
+}
+
+// parses a field of `line`, placing any error inside the line
+pub fn parse_field<T>(line: &str, field: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    field
+        .parse()
+        .map_err(|error: ParseError| ParseError::new(line, field, &error.message))
+}
+
+// parses every line on its own, numbering errors from `first_line`
+pub fn parse_lines<T>(input_string: &str, first_line: usize) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input_string
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|error: ParseError| error.on_line(first_line + index))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::parse::{parse_lines, parse_number, ParseError};
+
+    #[derive(Debug, PartialEq)]
+    struct Number(i32);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(line: &str) -> Result<Self, Self::Err> {
+            parse_number(line, line).map(Number)
+        }
+    }
+
+    #[test]
+    fn test_column() {
+        let line = "R 4x";
+        let error = parse_number::<i32>(line, &line[2..]).unwrap_err();
+
+        assert_eq!(error.column, 3);
+        assert_eq!(error.to_string(), "invalid number '4x'");
+        assert_eq!(
+            error.on_line(37).to_string(),
+            "line 37, column 3: invalid number '4x'"
+        );
+    }
+
+    #[test]
+    fn test_text_outside_line() {
+        assert_eq!(ParseError::new("abc", "xyz", "bad").column, 1);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1\n2\n3", 1),
+            Ok(vec![Number(1), Number(2), Number(3)])
+        );
+
+        let error = parse_lines::<Number>("1\n2\nthree", 1).unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "three");
+    }
+}
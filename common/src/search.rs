@@ -0,0 +1,272 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route<N, C> {
+    pub cost: C,
+    // from the start that was closest to the goal, both included
+    pub path: Vec<N>,
+}
+
+// every node that has been reached, with the index of the node it was reached from
+struct Nodes<N> {
+    nodes: Vec<(N, Option<usize>)>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Nodes<N> {
+    fn new() -> Self {
+        Nodes {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    // the index of `node`, and whether it was seen for the first time
+    fn insert(&mut self, node: N, parent: Option<usize>) -> (usize, bool) {
+        if let Some(&index) = self.indices.get(&node) {
+            return (index, false);
+        }
+
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push((node, parent));
+
+        (index, true)
+    }
+
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].0.clone()];
+
+        while let Some(parent) = self.nodes[index].1 {
+            path.push(self.nodes[parent].0.clone());
+            index = parent;
+        }
+
+        path.reverse();
+
+        path
+    }
+}
+
+// fewest steps from any of `starts` to a node passing `is_goal`
+pub fn bfs<N, S, I, F, G>(starts: S, mut neighbours: F, mut is_goal: G) -> Option<Route<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        let (index, new) = nodes.insert(start, None);
+
+        if new {
+            queue.push_back((index, 0));
+        }
+    }
+
+    while let Some((index, cost)) = queue.pop_front() {
+        let node = nodes.nodes[index].0.clone();
+
+        if is_goal(&node) {
+            return Some(Route {
+                cost,
+                path: nodes.path(index),
+            });
+        }
+
+        for neighbour in neighbours(&node) {
+            let (neighbour_index, new) = nodes.insert(neighbour, Some(index));
+
+            if new {
+                queue.push_back((neighbour_index, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+// cheapest route where `neighbours` gives every neighbour with the cost of moving there
+pub fn dijkstra<N, C, S, I, F, G>(starts: S, neighbours: F, is_goal: G) -> Option<Route<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+// dijkstra guided by `heuristic`, which must never overestimate the remaining cost
+pub fn astar<N, C, S, I, F, H, G>(
+    starts: S,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Route<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new();
+    let mut costs: Vec<C> = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let (index, new) = nodes.insert(start.clone(), None);
+
+        if new {
+            costs.push(C::default());
+            queue.push((Reverse(heuristic(&start)), C::default(), index));
+        }
+    }
+
+    // lowest estimate first, the furthest along of those first
+    while let Some((_, cost, index)) = queue.pop() {
+        // a cheaper way here was found after this entry was queued
+        if cost > costs[index] {
+            continue;
+        }
+
+        let node = nodes.nodes[index].0.clone();
+
+        if is_goal(&node) {
+            return Some(Route {
+                cost,
+                path: nodes.path(index),
+            });
+        }
+
+        for (neighbour, step_cost) in neighbours(&node) {
+            let neighbour_cost = cost + step_cost;
+            let (neighbour_index, new) = nodes.insert(neighbour.clone(), Some(index));
+
+            if new {
+                costs.push(neighbour_cost);
+            } else if neighbour_cost < costs[neighbour_index] {
+                costs[neighbour_index] = neighbour_cost;
+                nodes.nodes[neighbour_index].1 = Some(index);
+            } else {
+                continue;
+            }
+
+            queue.push((
+                Reverse(neighbour_cost + heuristic(&neighbour)),
+                neighbour_cost,
+                neighbour_index,
+            ));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{astar, bfs, dijkstra, Route};
+
+    // a -> b -> c -> d, with a shortcut from a to d that is expensive
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let neighbours = |&n: &i32| vec![n - 1, n + 1];
+
+        assert_eq!(
+            bfs([0], neighbours, |&n| n == 3),
+            Some(Route {
+                cost: 3,
+                path: vec![0, 1, 2, 3]
+            })
+        );
+
+        // the closest start wins
+        assert_eq!(
+            bfs([-10, 5], neighbours, |&n| n == 3),
+            Some(Route {
+                cost: 2,
+                path: vec![5, 4, 3]
+            })
+        );
+
+        assert_eq!(
+            bfs([3], neighbours, |&n| n == 3),
+            Some(Route {
+                cost: 0,
+                path: vec![3]
+            })
+        );
+
+        assert_eq!(bfs(['a'], |_| vec![], |&n| n == 'b'), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra(['a'], weighted, |&n| n == 'd'),
+            Some(Route {
+                cost: 6,
+                path: vec!['a', 'b', 'c', 'd']
+            })
+        );
+
+        assert_eq!(
+            bfs(
+                ['a'],
+                |n| weighted(n).into_iter().map(|(n, _)| n),
+                |&n| n == 'd'
+            )
+            .map(|route| route.path),
+            Some(vec!['a', 'd'])
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        // open 10x10 plane, the manhattan distance is exact so little off the route is expanded
+        let goal = (9, 9);
+        let mut expanded = 0;
+
+        let route = astar(
+            [(0, 0)],
+            |&(x, y): &(i32, i32)| {
+                expanded += 1;
+
+                [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                    .map(|point| (point, 1))
+            },
+            |&(x, y)| (goal.0 - x) + (goal.1 - y),
+            |&point| point == goal,
+        )
+        .unwrap();
+
+        assert_eq!(route.cost, 18);
+        assert_eq!(route.path.len(), 19);
+        assert!(expanded <= 19 * 2);
+    }
+}
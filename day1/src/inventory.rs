@@ -0,0 +1,265 @@
+use std::fmt::Write;
+
+use common::ParseError;
+
+use crate::{parse_elves, Elf};
+
+// wider spreads get wider buckets rather than more lines
+pub const MAX_BUCKETS: usize = 40;
+
+// every elf's load, for questions beyond who carries the most
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+    // the calories of every elf, least first
+    sorted: Vec<i64>,
+}
+
+impl Inventory {
+    pub fn new(elves: Vec<Elf>) -> Self {
+        let mut sorted = elves.iter().map(|elf| elf.calories).collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        Inventory { elves, sorted }
+    }
+
+    pub fn parse(input_string: &str) -> Result<Self, ParseError> {
+        Ok(Inventory::new(parse_elves(input_string)?))
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    pub fn items(&self) -> usize {
+        self.elves.iter().map(|elf| elf.items).sum()
+    }
+
+    pub fn calories(&self) -> i64 {
+        self.sorted.iter().sum()
+    }
+
+    pub fn carrying_more_than(&self, items: usize) -> impl Iterator<Item = &Elf> {
+        self.elves.iter().filter(move |elf| elf.items > items)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.calories() as f64 / self.len() as f64)
+    }
+
+    // the middle elf, or halfway between the two middle ones
+    pub fn median(&self) -> Option<f64> {
+        let middle = self.len() / 2;
+
+        match self.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(self.sorted[middle] as f64),
+            _ => Some((self.sorted[middle - 1] + self.sorted[middle]) as f64 / 2.0),
+        }
+    }
+
+    // nearest rank, the least load that at least `percent`% of the elves don't go over
+    pub fn percentile(&self, percent: f64) -> Option<i64> {
+        let rank = (percent / 100.0 * self.len() as f64).ceil() as usize;
+
+        self.sorted
+            .get(rank.clamp(1, self.len().max(1)) - 1)
+            .copied()
+    }
+
+    // how many elves carry `bucket` calories, `2 * bucket` calories and so on, one line per
+    // bucket with bars at most `width` long. the buckets are widened if there would be more
+    // than MAX_BUCKETS of them
+    pub fn histogram(&self, bucket: i64, width: usize) -> Result<String, String> {
+        if bucket <= 0 {
+            return Err(format!(
+                "buckets have to hold at least one calorie, not {}",
+                bucket
+            ));
+        }
+
+        let (Some(&least), Some(&most)) = (self.sorted.first(), self.sorted.last()) else {
+            return Ok(String::new());
+        };
+
+        // a bucket this wide makes range / bucket at most MAX_BUCKETS - 2, and the ends can
+        // add one more bucket each
+        let range = most as i128 - least as i128;
+        let bucket = bucket.max((range / (MAX_BUCKETS as i128 - 1) + 1) as i64);
+
+        // rounding down, so negative totals go in the bucket below zero
+        let first = least.div_euclid(bucket);
+        let last = most.div_euclid(bucket);
+        let mut counts = vec![0usize; (last - first + 1) as usize];
+
+        for calories in &self.sorted {
+            counts[(calories.div_euclid(bucket) - first) as usize] += 1;
+        }
+
+        let highest = counts.iter().copied().max().unwrap_or(0);
+        // the bounds can go past i64 when the buckets are wide
+        let bound = |index: i64| index as i128 * bucket as i128;
+        let digits = [bound(first), bound(last + 1) - 1]
+            .iter()
+            .map(|bound| bound.to_string().len())
+            .max()
+            .unwrap();
+        let mut histogram = String::new();
+
+        for (offset, &count) in counts.iter().enumerate() {
+            let from = bound(first + offset as i64);
+            // rounded up so every bucket with an elf gets a bar
+            let bar = (count * width).div_ceil(highest);
+
+            writeln!(
+                histogram,
+                "{:>digits$}-{:>digits$} {:<width$} {}",
+                from,
+                from + bucket as i128 - 1,
+                "#".repeat(bar),
+                count,
+            )
+            .unwrap();
+        }
+
+        Ok(histogram)
+    }
+
+    // the statistics, every elf carrying more than `more_than` items and a histogram
+    pub fn report(&self, bucket: i64, more_than: usize) -> Result<String, String> {
+        let histogram = self.histogram(bucket, 40)?;
+        let mut report = String::new();
+        let number = |value: Option<f64>| value.map_or("-".to_owned(), |v| format!("{:.1}", v));
+
+        writeln!(report, "elves     {}", self.len()).unwrap();
+        writeln!(report, "items     {}", self.items()).unwrap();
+        writeln!(report, "calories  {}", self.calories()).unwrap();
+        writeln!(report, "mean      {}", number(self.mean())).unwrap();
+        writeln!(report, "median    {}", number(self.median())).unwrap();
+
+        for percent in [10, 25, 75, 90] {
+            let value = self
+                .percentile(percent as f64)
+                .map_or("-".to_owned(), |value| value.to_string());
+
+            writeln!(report, "p{:<8} {}", percent, value).unwrap();
+        }
+
+        if let Some(most) = self.elves.iter().max_by_key(|elf| elf.calories) {
+            writeln!(report, "most      {} (elf {})", most.calories, most.index).unwrap();
+        }
+
+        writeln!(report).unwrap();
+        writeln!(report, "{:>6} {:>6} {:>10}", "elf", "items", "calories").unwrap();
+
+        for elf in self.carrying_more_than(more_than) {
+            writeln!(
+                report,
+                "{:>6} {:>6} {:>10}",
+                elf.index, elf.items, elf.calories
+            )
+            .unwrap();
+        }
+
+        writeln!(report).unwrap();
+        report.push_str(&histogram);
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        inventory::{Inventory, MAX_BUCKETS},
+        Elf,
+    };
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day01/example.txt");
+
+    #[test]
+    fn test_statistics() {
+        let inventory = Inventory::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(inventory.len(), 5);
+        assert_eq!(inventory.items(), 10);
+        assert_eq!(inventory.calories(), 55_000);
+        assert_eq!(inventory.mean(), Some(11_000.0));
+        assert_eq!(inventory.median(), Some(10_000.0));
+        assert_eq!(inventory.percentile(20.0), Some(4_000));
+        assert_eq!(inventory.percentile(50.0), Some(10_000));
+        assert_eq!(inventory.percentile(90.0), Some(24_000));
+        assert_eq!(inventory.percentile(0.0), Some(4_000));
+        assert_eq!(
+            inventory
+                .carrying_more_than(2)
+                .map(|elf| elf.index)
+                .collect::<Vec<_>>(),
+            vec![0, 3]
+        );
+
+        let even = Inventory::parse("1\n\n4\n\n2\n\n9").unwrap();
+        assert_eq!(even.median(), Some(3.0));
+
+        let empty = Inventory::parse("").unwrap();
+        assert_eq!(
+            (empty.mean(), empty.median(), empty.percentile(50.0)),
+            (None, None, None)
+        );
+        assert_eq!(empty.histogram(10, 10), Ok(String::new()));
+    }
+
+    #[test]
+    fn test_histogram() {
+        let inventory = Inventory::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            inventory.histogram(5_000, 4),
+            Ok("    0- 4999 ##   1\n 5000- 9999 ##   1\n10000-14999 #### 2\n15000-19999      0\n20000-24999 ##   1\n".to_owned())
+        );
+        assert!(inventory.histogram(0, 4).is_err());
+
+        // a huge spread is split into at most MAX_BUCKETS buckets
+        let spread = Inventory::parse("0\n\n1000000000000").unwrap();
+        let histogram = spread.histogram(5_000, 4).unwrap();
+        assert!(histogram.lines().count() <= MAX_BUCKETS);
+        assert!(histogram.starts_with("            0-  25641025641 #### 1\n"));
+
+        let extremes = Inventory::new(vec![
+            Elf {
+                index: 0,
+                calories: i64::MIN,
+                items: 1,
+            },
+            Elf {
+                index: 1,
+                calories: i64::MAX,
+                items: 1,
+            },
+        ]);
+        assert!(extremes.histogram(1, 4).unwrap().lines().count() <= MAX_BUCKETS);
+
+        let negative = Inventory::parse("-1\n\n-10\n\n3").unwrap();
+        assert_eq!(
+            negative.histogram(10, 2),
+            Ok("-10- -1 ## 2\n  0-  9 #  1\n".to_owned())
+        );
+
+        let report = inventory.report(5_000, 2).unwrap();
+        assert!(report.starts_with("elves     5\nitems     10\n"));
+        assert!(report.contains("median    10000.0\n"));
+        assert!(report.contains("most      24000 (elf 3)\n"));
+        // only the elves with three items
+        assert!(report.contains(
+            "   elf  items   calories\n     0      3       6000\n     3      3      24000\n\n"
+        ));
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{self, BufRead},
+};
+
+use common::{parse::parse_number, Answer, ParseError, Solution};
+
+pub mod inventory;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    // counted from 0 in the order the elves are listed
+    pub index: usize,
+    pub calories: i64,
+    // how many food items the calories are spread over
+    pub items: usize,
+}
+
+// the elves in a list of numbers with a blank line after each elf, read a line at a time so
+// the whole list never has to be in memory. invalid numbers are InvalidData errors holding a
+// ParseError
+pub struct Groups<R> {
+    lines: io::Lines<R>,
+    line_number: usize,
+    index: usize,
+}
+
+pub fn groups<R: BufRead>(reader: R) -> Groups<R> {
+    Groups {
+        lines: reader.lines(),
+        line_number: 0,
+        index: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Groups<R> {
+    type Item = io::Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut calories = None;
+        let mut items = 0;
+
+        for line in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => return Some(Err(error)),
+            };
+
+            self.line_number += 1;
+            let text = line.trim();
+
+            if text.is_empty() {
+                // extra blank lines don't make empty elves
+                if calories.is_some() {
+                    break;
+                }
+
+                continue;
+            }
+
+            match parse_number::<i64>(text, text) {
+                Ok(number) => {
+                    *calories.get_or_insert(0) += number;
+                    items += 1;
+                }
+                Err(error) => {
+                    let error = error.on_line(self.line_number);
+
+                    return Some(Err(io::Error::new(io::ErrorKind::InvalidData, error)));
+                }
+            }
+        }
+
+        let elf = Elf {
+            index: self.index,
+            calories: calories?,
+            items,
+        };
+        self.index += 1;
+
+        Some(Ok(elf))
+    }
+}
+
+// the `k` elves carrying the most, most first, keeping only `k` of them at any time. ties go
+// to the elf listed first, and there are fewer than `k` if there aren't that many elves
+pub fn top_k<I>(elves: I, k: usize) -> Vec<Elf>
+where
+    I: IntoIterator<Item = Elf>,
+{
+    // the least of the best so far is at the top, k can be huge when it means every elf
+    let mut heap = BinaryHeap::with_capacity(k.saturating_add(1).min(1024));
+
+    for elf in elves {
+        heap.push(Reverse((elf.calories, Reverse(elf.index), elf.items)));
+
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(index), items))| Elf {
+            index,
+            calories,
+            items,
+        })
+        .collect()
+}
+
+pub fn read_top_k<R: BufRead>(reader: R, k: usize) -> io::Result<Vec<Elf>> {
+    let mut error = None;
+
+    let top = top_k(
+        groups(reader).map_while(|elf| elf.map_err(|e| error = Some(e)).ok()),
+        k,
+    );
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(top),
+    }
+}
+
+pub fn parse_elves(input_string: &str) -> Result<Vec<Elf>, ParseError> {
+    groups(input_string.as_bytes())
+        .map(|elf| {
+            elf.map_err(|error| {
+                // reading from a string can only fail on the numbers
+                *error
+                    .into_inner()
+                    .and_then(|error| error.downcast::<ParseError>().ok())
+                    .expect("not a parse error")
+            })
+        })
+        .collect()
+}
+
+fn total(elves: &[Elf]) -> i64 {
+    elves.iter().map(|elf| elf.calories).sum()
+}
+
+pub fn solve_part_1(input: &str) -> Result<i64, ParseError> {
+    Ok(total(&top_k(parse_elves(input)?, 1)))
+}
+
+pub fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    Ok(total(&top_k(parse_elves(input)?, 3)))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Elf>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_elves(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        total(&top_k(input.iter().copied(), 1)).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        total(&top_k(input.iter().copied(), 3)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_elves, read_top_k, solve_part_1, solve_part_2, top_k, Elf};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day01/example.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part_1(TEST_INPUT), Ok(24_000));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part_2(TEST_INPUT), Ok(45_000));
+    }
+
+    #[test]
+    fn test_top_k() {
+        let elf = |index, calories, items| Elf {
+            index,
+            calories,
+            items,
+        };
+
+        assert_eq!(
+            read_top_k(TEST_INPUT.as_bytes(), 2).unwrap(),
+            vec![elf(3, 24_000, 3), elf(2, 11_000, 2)]
+        );
+
+        // more than there are elves, and the first listed winning a tie
+        assert_eq!(
+            read_top_k("1\n2\n\n\n\n3\n\n3\n".as_bytes(), 5).unwrap(),
+            vec![elf(0, 3, 2), elf(1, 3, 1), elf(2, 3, 1)]
+        );
+        assert_eq!(solve_part_2("100\n\n200"), Ok(300));
+        assert_eq!(top_k(parse_elves(TEST_INPUT).unwrap(), 0), vec![]);
+        assert_eq!(top_k(parse_elves(TEST_INPUT).unwrap(), usize::MAX).len(), 5);
+        assert_eq!(read_top_k("".as_bytes(), 3).unwrap(), vec![]);
+
+        let error = read_top_k("1\n\n2\nx\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 1: invalid number 'x'");
+
+        let error = solve_part_1("1\n\n2\nx\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 1: invalid number 'x'");
+    }
+}
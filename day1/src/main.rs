@@ -1,68 +1,85 @@
-use std::{fs::File, io::Read};
+use std::{env, io, iter::Peekable, process, str::FromStr};
 
-fn solve_part_1(input: &str) -> i32 {
-    let parts = input.split("\n\n");
-    let segment_sums = parts.map(|part| {
-        let numbers = part.lines().map(|line| line.parse::<i32>().unwrap());
-        numbers.sum::<i32>()
-    });
+use common::input::{day_file_name, Source};
+use day1::{groups, inventory::Inventory, read_top_k};
 
-    segment_sums.max().unwrap()
-}
+// the input is read a line at a time, so it never has to fit in memory as text
+const USAGE: &str = "usage:
+  day1 [<path>|-]
+  day1 report [--bucket <calories>] [--more-than <items>] [<path>|-]";
 
-fn solve_part_2(input: &str) -> i32 {
-    let parts = input.split("\n\n");
-    let mut segment_sums = parts
-        .map(|part| {
-            let numbers = part.lines().map(|line| line.parse::<i32>().unwrap());
-            numbers.sum::<i32>()
-        })
-        .collect::<Vec<_>>();
+struct ReportArgs {
+    bucket: i64,
+    more_than: usize,
+}
 
-    //sort desc
-    segment_sums.sort_by(|a, b| b.cmp(a));
+fn parse_value<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {}", flag))?;
 
-    segment_sums[..3].iter().sum()
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {}: {}", flag, value))
 }
 
-fn main() {
-    let mut input_string = String::new();
-    File::open("src/input.txt")
-        .unwrap()
-        .read_to_string(&mut input_string)
-        .unwrap();
-
-    println!("Part 1: {}", solve_part_1(&input_string));
+// the flags before the input, every elf carries more than 0 items
+fn parse_report_args<I>(args: &mut Peekable<I>) -> Result<ReportArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut report_args = ReportArgs {
+        bucket: 5_000,
+        more_than: 0,
+    };
+
+    while let Some(flag) = args.next_if(|arg| arg.starts_with("--")) {
+        match flag.as_str() {
+            "--bucket" => report_args.bucket = parse_value(&flag, args.next())?,
+            "--more-than" => report_args.more_than = parse_value(&flag, args.next())?,
+            _ => return Err(format!("unknown argument: {}", flag)),
+        }
+    }
 
-    println!("Part 2: {}", solve_part_2(&input_string));
+    Ok(report_args)
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{solve_part_1, solve_part_2};
-
-    const TEST_INPUT: &str = "1000
-2000
-3000
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    let report_args = match args.next_if(|arg| arg == "report") {
+        Some(_) => match parse_report_args(&mut args) {
+            Ok(report_args) => Some(report_args),
+            Err(message) => {
+                eprintln!("{}\n{}", message, USAGE);
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    let reader = Source::from_arg(args.next().as_deref(), &day_file_name(1))
+        .open()
+        .unwrap();
 
-4000
+    if let Some(ReportArgs { bucket, more_than }) = report_args {
+        let elves = groups(reader).collect::<io::Result<Vec<_>>>().unwrap();
 
-5000
-6000
+        match Inventory::new(elves).report(bucket, more_than) {
+            Ok(report) => print!("{}", report),
+            Err(message) => {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        }
 
-7000
-8000
-9000
+        return;
+    }
 
-10000";
+    // the most loaded elf is the first of the top three
+    let top = read_top_k(reader, 3).unwrap();
 
-    #[test]
-    fn part1() {
-        assert_eq!(solve_part_1(TEST_INPUT), 24_000);
-    }
+    println!("Part 1: {}", top.first().map_or(0, |elf| elf.calories));
 
-    #[test]
-    fn part2() {
-        assert_eq!(solve_part_2(TEST_INPUT), 45_000);
-    }
+    println!(
+        "Part 2: {}",
+        top.iter().map(|elf| elf.calories).sum::<i64>()
+    );
 }
@@ -0,0 +1,226 @@
+use std::{
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use common::{parse::parse_number, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    X,
+}
+
+const REGISTER_COUNT: usize = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers([i32; REGISTER_COUNT]);
+
+impl Registers {
+    pub fn new() -> Self {
+        // X starts at 1
+        Registers([1])
+    }
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Registers::new()
+    }
+}
+
+impl Index<Register> for Registers {
+    type Output = i32;
+
+    fn index(&self, register: Register) -> &i32 {
+        &self.0[register as usize]
+    }
+}
+
+impl IndexMut<Register> for Registers {
+    fn index_mut(&mut self, register: Register) -> &mut i32 {
+        &mut self.0[register as usize]
+    }
+}
+
+// a new opcode needs a variant, its name in from_str, its cycle count and what it does, the
+// cpu itself doesn't know about any of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Noop,
+    AddX(i32),
+}
+
+impl Operation {
+    pub fn cycle_count(&self) -> i32 {
+        match self {
+            Operation::Noop => 1,
+            Operation::AddX(_) => 2,
+        }
+    }
+
+    // takes effect at the end of the last cycle
+    pub fn execute(&self, registers: &mut Registers) {
+        match self {
+            Operation::Noop => {}
+            Operation::AddX(value) => registers[Register::X] += value,
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (operation, argument) = match input.split_once(' ') {
+            Some((operation, argument)) => (operation, Some(argument)),
+            None => (input, None),
+        };
+
+        match (operation, argument) {
+            ("noop", None) => Ok(Operation::Noop),
+            ("addx", Some(argument)) => Ok(Operation::AddX(parse_number(input, argument)?)),
+            ("addx", None) => Err(ParseError::new(input, input, "missing argument")),
+            ("noop", Some(argument)) => {
+                Err(ParseError::new(input, argument, "unexpected argument"))
+            }
+            _ => Err(ParseError::new(input, operation, "unknown operation")),
+        }
+    }
+}
+
+// the registers as they are during a cycle, before the instruction finishing in it takes
+// effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    // counted from 1
+    pub number: i32,
+    pub registers: Registers,
+}
+
+impl Cycle {
+    pub fn x(&self) -> i32 {
+        self.registers[Register::X]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cpu<'a> {
+    program: &'a [Operation],
+    // the instruction being executed, and how many of its cycles have passed
+    counter: usize,
+    elapsed: i32,
+    clock: i32,
+    registers: Registers,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Operation]) -> Self {
+        Cpu {
+            program,
+            counter: 0,
+            elapsed: 0,
+            clock: 0,
+            registers: Registers::new(),
+        }
+    }
+
+    // the number of cycles that have finished
+    pub fn clock(&self) -> i32 {
+        self.clock
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.counter >= self.program.len()
+    }
+
+    // runs one cycle, None once the program has finished
+    pub fn step(&mut self) -> Option<Cycle> {
+        let operation = self.program.get(self.counter)?;
+
+        self.clock += 1;
+        let cycle = Cycle {
+            number: self.clock,
+            registers: self.registers,
+        };
+
+        self.elapsed += 1;
+
+        if self.elapsed == operation.cycle_count() {
+            operation.execute(&mut self.registers);
+            self.counter += 1;
+            self.elapsed = 0;
+        }
+
+        Some(cycle)
+    }
+
+    // runs to the end, calling `observer` during every cycle
+    pub fn run<F>(&mut self, mut observer: F)
+    where
+        F: FnMut(&Cycle),
+    {
+        while let Some(cycle) = self.step() {
+            observer(&cycle);
+        }
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = Cycle;
+
+    fn next(&mut self) -> Option<Cycle> {
+        self.step()
+    }
+}
+
+// (cycle, X during it) for every cycle of the program
+pub fn trace(program: &[Operation]) -> Vec<(i32, i32)> {
+    Cpu::new(program)
+        .map(|cycle| (cycle.number, cycle.x()))
+        .collect()
+}
+
+pub fn format_trace(trace: &[(i32, i32)]) -> String {
+    trace
+        .iter()
+        .map(|(cycle, x)| format!("{:>4} {}\n", cycle, x))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::{format_trace, trace, Cpu, Operation, Register};
+
+    const PROGRAM: [Operation; 3] = [Operation::Noop, Operation::AddX(3), Operation::AddX(-5)];
+
+    #[test]
+    fn test_step() {
+        let mut cpu = Cpu::new(&PROGRAM);
+
+        assert_eq!(cpu.step().map(|cycle| cycle.x()), Some(1));
+        assert_eq!(cpu.step().map(|cycle| cycle.x()), Some(1));
+        // addx 3 only takes effect after its second cycle
+        assert_eq!(cpu.step().map(|cycle| cycle.x()), Some(1));
+        assert_eq!(cpu.registers()[Register::X], 4);
+        assert_eq!(cpu.clock(), 3);
+
+        let mut observed = Vec::new();
+        cpu.run(|cycle| observed.push(cycle.number));
+        assert_eq!(observed, vec![4, 5]);
+        assert!(cpu.is_halted());
+        assert_eq!(cpu.step(), None);
+        assert_eq!(cpu.registers()[Register::X], -1);
+    }
+
+    #[test]
+    fn test_trace() {
+        let trace = trace(&PROGRAM);
+
+        assert_eq!(trace, vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+        assert_eq!(format_trace(&trace[..2]), "   1 1\n   2 1\n");
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt;
+
+use common::{
+    grid::{Grid, Point},
+    ParseError,
+};
+
+use crate::cpu::{Cpu, Operation};
+
+// the screen of the handheld device
+pub const WIDTH: usize = 40;
+pub const HEIGHT: usize = 6;
+
+// one bit per pixel, true when the pixel is lit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Framebuffer {
+    pixels: Grid<bool>,
+}
+
+impl Framebuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Framebuffer {
+            pixels: Grid::new(width, height, false),
+        }
+    }
+
+    // rows of '#' and '.', the way the puzzle draws the screen
+    pub fn from_art(art: &str) -> Result<Self, ParseError> {
+        let pixels = Grid::parse(art.trim_matches('\n'), |pixel| match pixel {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Framebuffer { pixels })
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn get(&self, point: Point) -> bool {
+        self.pixels.get(point).copied().unwrap_or(false)
+    }
+
+    pub fn set(&mut self, point: Point, lit: bool) {
+        self.pixels[point] = lit;
+    }
+
+    pub fn lit(&self) -> impl Iterator<Item = Point> + '_ {
+        self.pixels
+            .iter()
+            .filter(|(_, &lit)| lit)
+            .map(|(point, _)| point)
+    }
+
+    // the pixels that differ from `expected`, which has to be the same size
+    pub fn differences(&self, expected: &Framebuffer) -> Result<Vec<Point>, String> {
+        if (self.width(), self.height()) != (expected.width(), expected.height()) {
+            return Err(format!(
+                "expected {}x{} pixels, found {}x{}",
+                expected.width(),
+                expected.height(),
+                self.width(),
+                self.height()
+            ));
+        }
+
+        Ok(self
+            .pixels
+            .iter()
+            .filter(|&(point, &lit)| lit != expected.get(point))
+            .map(|(point, _)| point)
+            .collect())
+    }
+
+    // plain PBM, where 1 is black so lit pixels come out dark on white
+    pub fn to_pbm(&self) -> String {
+        let mut pbm = format!("P1\n{} {}\n", self.width(), self.height());
+
+        // plain pbm allows bits without separators but no lines over 70 characters
+        for row in self.pixels.rows() {
+            for chunk in row.chunks(70) {
+                pbm.extend(chunk.iter().map(|&lit| if lit { '1' } else { '0' }));
+                pbm.push('\n');
+            }
+        }
+
+        pbm
+    }
+
+    // one square of `scale` by `scale` per lit pixel
+    pub fn to_svg(&self, scale: usize) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+            self.width() * scale,
+            self.height() * scale,
+            self.width(),
+            self.height()
+        );
+
+        for (x, y) in self.lit() {
+            svg.push_str(&format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\"/>\n",
+                x, y
+            ));
+        }
+
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+}
+
+impl fmt::Display for Framebuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pixels.map(|&lit| if lit { '#' } else { '.' }))
+    }
+}
+
+// the crt draws one pixel per cycle row by row, lighting it when the three pixel wide sprite
+// centred on X covers it
+pub fn render(operations: &[Operation], width: usize, height: usize) -> Framebuffer {
+    let mut framebuffer = Framebuffer::new(width, height);
+
+    // there are no pixels to light
+    if width == 0 || height == 0 {
+        return framebuffer;
+    }
+
+    Cpu::new(operations).run(|cycle| {
+        let position = (cycle.number - 1) as usize;
+        let point = (position % width, position / width);
+
+        if point.1 < height && (cycle.x() - point.0 as i32).abs() <= 1 {
+            framebuffer.set(point, true);
+        }
+    });
+
+    framebuffer
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crt::Framebuffer;
+
+    #[test]
+    fn test_framebuffer() {
+        let framebuffer = Framebuffer::from_art("\n#..\n.#.\n").unwrap();
+
+        assert_eq!((framebuffer.width(), framebuffer.height()), (3, 2));
+        assert_eq!(framebuffer.to_string(), "#..\n.#.");
+        assert_eq!(framebuffer.lit().collect::<Vec<_>>(), vec![(0, 0), (1, 1)]);
+
+        let mut other = Framebuffer::new(3, 2);
+        other.set((0, 0), true);
+        assert_eq!(framebuffer.differences(&other), Ok(vec![(1, 1)]));
+        assert!(framebuffer.differences(&Framebuffer::new(2, 2)).is_err());
+
+        let error = Framebuffer::from_art("#.\n.x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        let framebuffer = Framebuffer::from_art("#..\n.#.").unwrap();
+
+        assert_eq!(framebuffer.to_pbm(), "P1\n3 2\n100\n010\n");
+
+        let wide = Framebuffer::new(75, 1).to_pbm();
+        assert_eq!(wide, format!("P1\n75 1\n{}\n00000\n", "0".repeat(70)));
+        assert_eq!(
+            framebuffer.to_svg(10),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"20\" viewBox=\"0 0 3 2\">\n  \
+             <rect x=\"0\" y=\"0\" width=\"1\" height=\"1\"/>\n  \
+             <rect x=\"1\" y=\"1\" width=\"1\" height=\"1\"/>\n\
+             </svg>\n"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use common::{parse::parse_lines, Answer, ParseError, Solution};
+
+use cpu::Cpu;
+use crt::Framebuffer;
+
+pub mod cpu;
+pub mod crt;
+pub mod ocr;
+
+pub use cpu::Operation;
+
+pub const CHECKPOINTS: [i32; 6] = [20, 60, 100, 140, 180, 220];
+
+pub fn parse_operations(input_string: &str) -> Result<Vec<Operation>, ParseError> {
+    parse_lines(input_string, 1)
+}
+
+// the sum of cycle * X during each of the checkpoint cycles
+pub fn signal_strength(operations: &[Operation], checkpoints: &[i32]) -> i32 {
+    let mut strength = 0;
+
+    Cpu::new(operations).run(|cycle| {
+        if checkpoints.contains(&cycle.number) {
+            strength += cycle.number * cycle.x();
+        }
+    });
+
+    strength
+}
+
+pub fn part1(operations: &[Operation]) -> i32 {
+    signal_strength(operations, &CHECKPOINTS)
+}
+
+pub fn part2(operations: &[Operation]) -> Framebuffer {
+    crt::render(operations, crt::WIDTH, crt::HEIGHT)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Operation>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_operations(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let screen = part2(input);
+
+        // anything that isn't letters is left for a human to look at
+        match ocr::read(&screen) {
+            Ok(letters) => Answer::Text(letters),
+            Err(_) => Answer::Bitmap(screen.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        crt::{self, Framebuffer},
+        parse_operations, part1, part2, signal_strength, Operation,
+    };
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day10/example.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_operations(TEST_INPUT).unwrap()), 13140);
+    }
+
+    #[test]
+    fn test_signal_strength() {
+        let operations = parse_operations(TEST_INPUT).unwrap();
+
+        assert_eq!(signal_strength(&operations, &[20]), 420);
+        assert_eq!(signal_strength(&operations, &[20, 220]), 420 + 3960);
+        assert_eq!(signal_strength(&operations, &[]), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let expected =
+            Framebuffer::from_art(include_str!("../../fixtures/day10/example.part2.txt")).unwrap();
+        let framebuffer = part2(&parse_operations(TEST_INPUT).unwrap());
+
+        assert_eq!(framebuffer.differences(&expected), Ok(vec![]));
+        assert_eq!(framebuffer, expected);
+
+        // a smaller screen just shows the first cycles
+        let small = crt::render(&parse_operations(TEST_INPUT).unwrap(), 10, 2);
+        assert_eq!(small.to_string(), "##..##..##\n....##....");
+
+        for (width, height) in [(0, 6), (40, 0), (0, 0)] {
+            let empty = crt::render(&parse_operations(TEST_INPUT).unwrap(), width, height);
+            assert_eq!((empty.width(), empty.height()), (width, height));
+        }
+    }
+
+    #[test]
+    fn test_parse_operations() {
+        assert_eq!(
+            parse_operations("noop\naddx -11"),
+            Ok(vec![Operation::Noop, Operation::AddX(-11)])
+        );
+
+        let error = parse_operations("noop\nmulx 3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unknown operation 'mulx'"
+        );
+
+        let error = parse_operations("addx 1\naddx x").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 6: invalid number 'x'");
+    }
+}
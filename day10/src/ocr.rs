@@ -0,0 +1,122 @@
+use std::{error::Error, fmt};
+
+use crate::crt::Framebuffer;
+
+// letters are 4 pixels wide with a blank column after each, and as tall as the screen
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+// the letters that have turned up in puzzle answers
+const FONT: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    // counted from 0, left to right
+    pub index: usize,
+    // rows of '#' and '.'
+    pub bitmap: String,
+}
+
+impl fmt::Display for UnknownGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown glyph {}:\n{}", self.index, self.bitmap)
+    }
+}
+
+impl Error for UnknownGlyph {}
+
+// the glyph starting at column `left` as one string of '#' and '.', row by row
+fn glyph(framebuffer: &Framebuffer, left: usize) -> String {
+    (0..GLYPH_HEIGHT)
+        .flat_map(|y| (left..left + GLYPH_WIDTH).map(move |x| (x, y)))
+        .map(|point| if framebuffer.get(point) { '#' } else { '.' })
+        .collect()
+}
+
+pub fn read(framebuffer: &Framebuffer) -> Result<String, UnknownGlyph> {
+    // the last letter doesn't need its blank column
+    let count = (framebuffer.width() + 1) / (GLYPH_WIDTH + 1);
+
+    (0..count)
+        .map(|index| {
+            let glyph = glyph(framebuffer, index * (GLYPH_WIDTH + 1));
+
+            FONT.iter()
+                .find(|(_, pixels)| *pixels == glyph)
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| UnknownGlyph {
+                    index,
+                    bitmap: glyph
+                        .as_bytes()
+                        .chunks(GLYPH_WIDTH)
+                        .map(|row| String::from_utf8_lossy(row))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        crt::Framebuffer,
+        ocr::{read, UnknownGlyph, FONT, GLYPH_WIDTH},
+    };
+
+    // the letters side by side, with a blank column after each
+    fn draw(text: &str) -> Framebuffer {
+        let rows = (0..6)
+            .map(|y| {
+                text.chars()
+                    .map(|letter| {
+                        let (_, pixels) = FONT.iter().find(|(c, _)| *c == letter).unwrap();
+
+                        format!("{}.", &pixels[y * GLYPH_WIDTH..(y + 1) * GLYPH_WIDTH])
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        Framebuffer::from_art(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_read() {
+        let letters = FONT.iter().map(|(letter, _)| letter).collect::<String>();
+
+        assert_eq!(read(&draw(&letters)), Ok(letters));
+        assert_eq!(read(&draw("EFUGLPAP")), Ok("EFUGLPAP".to_owned()));
+
+        let mut framebuffer = draw("HI");
+        framebuffer.set((5, 0), true);
+
+        let error = read(&framebuffer).unwrap_err();
+        assert_eq!(
+            error,
+            UnknownGlyph {
+                index: 1,
+                bitmap: "####\n..#.\n..#.\n..#.\n..#.\n.###".to_owned()
+            }
+        );
+        assert!(error.to_string().starts_with("unknown glyph 1:\n####\n"));
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+// a non-negative integer of any size, as base 2^32 digits with the least significant first and
+// no trailing zeroes, so zero has no digits at all
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BigUint {
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { digits: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    fn trim(mut self) -> Self {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+
+        self
+    }
+
+    pub fn bits(&self) -> usize {
+        match self.digits.last() {
+            Some(last) => self.digits.len() * 32 - last.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.digits.len() >= other.digits.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let mut digits = Vec::with_capacity(long.digits.len() + 1);
+        let mut carry = 0;
+
+        for (index, &digit) in long.digits.iter().enumerate() {
+            let sum = digit as u64 + *short.digits.get(index).unwrap_or(&0) as u64 + carry;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            digits.push(carry as u32);
+        }
+
+        BigUint { digits }
+    }
+
+    // schoolbook multiplication, the numbers here stay at a few thousand digits
+    pub fn mul(&self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+
+        let mut digits = vec![0u32; self.digits.len() + other.digits.len()];
+
+        for (i, &a) in self.digits.iter().enumerate() {
+            let mut carry = 0;
+
+            for (j, &b) in other.digits.iter().enumerate() {
+                let product = a as u64 * b as u64 + digits[i + j] as u64 + carry;
+                digits[i + j] = product as u32;
+                carry = product >> 32;
+            }
+
+            digits[i + other.digits.len()] = carry as u32;
+        }
+
+        BigUint { digits }.trim()
+    }
+
+    // the quotient and remainder of dividing by a single digit
+    pub fn div_rem(&self, divisor: u32) -> (BigUint, u32) {
+        assert!(divisor != 0, "division by zero");
+
+        let mut digits = vec![0u32; self.digits.len()];
+        let mut remainder = 0u64;
+
+        for (index, &digit) in self.digits.iter().enumerate().rev() {
+            let current = (remainder << 32) | digit as u64;
+            digits[index] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+
+        (BigUint { digits }.trim(), remainder as u32)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint {
+            digits: vec![value as u32, (value >> 32) as u32],
+        }
+        .trim()
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // peel off nine decimal digits at a time, least significant first
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem(1_000_000_000);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+
+        write!(f, "{}", chunks.pop().unwrap())?;
+
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:09}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bignum::BigUint;
+
+    fn big(value: u128) -> BigUint {
+        BigUint::from((value >> 64) as u64)
+            .mul(&BigUint::from(1 << 32).mul(&BigUint::from(1 << 32)))
+            .add(&BigUint::from(value as u64))
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = 0xffff_ffff_ffff_ffff_u128;
+        let b = 123_456_789_012_345_u128;
+
+        assert_eq!(big(a).add(&big(b)), big(a + b));
+        assert_eq!(big(a).mul(&big(3)), big(a * 3));
+        assert_eq!(big(b).mul(&big(b)), big(b * b));
+        assert_eq!(big(a).mul(&BigUint::zero()), BigUint::zero());
+        assert_eq!(big(a * 7 + 5).div_rem(7), (big(a), 5));
+        assert_eq!(big(a).bits(), 64);
+        assert_eq!(BigUint::zero().bits(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(big(1_000_000_000).to_string(), "1000000000");
+        assert_eq!(big(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+}
@@ -0,0 +1,742 @@
+use std::{collections::HashMap, mem, str::FromStr};
+
+use common::{parse::parse_number, Answer, ParseError, Solution};
+
+use bignum::BigUint;
+
+pub mod bignum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Constant(i64),
+}
+
+// the right hand side of "new = old * 19"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand),
+    Multiply(Operand),
+}
+
+impl Operation {
+    pub fn apply<W: Worry>(self, old: &W) -> W {
+        let value = |operand| match operand {
+            Operand::Old => old.clone(),
+            Operand::Constant(constant) => W::from_i64(constant),
+        };
+
+        match self {
+            Operation::Add(operand) => old.add(&value(operand)),
+            Operation::Multiply(operand) => old.mul(&value(operand)),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let expected = || ParseError::new(text, text, "expected 'new = old <+|*> <old|number>'");
+
+        let (operator, operand) = text
+            .strip_prefix("new = old ")
+            .and_then(|rest| rest.split_once(' '))
+            .ok_or_else(expected)?;
+
+        let operand = match operand {
+            "old" => Operand::Old,
+            _ => Operand::Constant(parse_number(text, operand)?),
+        };
+
+        match operator {
+            "+" => Ok(Operation::Add(operand)),
+            "*" => Ok(Operation::Multiply(operand)),
+            _ => Err(ParseError::new(text, operator, "unknown operator")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    pub items: Vec<i64>,
+    pub operation: Operation,
+    // items divisible by this go to true_target, the rest to false_target
+    pub divisor: i64,
+    pub true_target: usize,
+    pub false_target: usize,
+}
+
+impl Monkey {
+    // where an item with worry level `level` is thrown
+    fn target<W: Worry>(&self, level: &W) -> usize {
+        if level.rem(self.divisor) == 0 {
+            self.true_target
+        } else {
+            self.false_target
+        }
+    }
+}
+
+// the text after `label` on the line, with errors placed on line `number`
+fn field<'a>(lines: &[(usize, &'a str)], index: usize, label: &str) -> Result<&'a str, ParseError> {
+    let (number, line) = lines
+        .get(index)
+        .copied()
+        .ok_or_else(|| ParseError::new("", "", &format!("missing '{}'", label)))?;
+
+    line.trim_start().strip_prefix(label).ok_or_else(|| {
+        ParseError::new(line, line, &format!("expected '{}'", label)).on_line(number)
+    })
+}
+
+fn parse_monkey(lines: &[(usize, &str)], index: usize, count: usize) -> Result<Monkey, ParseError> {
+    // moves an error placed inside the field `text` to its place in the whole line
+    fn on<T>(
+        lines: &[(usize, &str)],
+        field_index: usize,
+        text: &str,
+        result: Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        result.map_err(|error| {
+            let (number, line) = lines[field_index];
+            let offset = ParseError::new(line, text, "").column - 1;
+
+            ParseError {
+                column: error.column + offset,
+                ..error
+            }
+            .on_line(number)
+        })
+    }
+
+    let header = field(lines, 0, "Monkey ")?;
+    if header != format!("{}:", index) {
+        let (number, line) = lines[0];
+
+        return Err(
+            ParseError::new(line, header, &format!("expected monkey {}", index)).on_line(number),
+        );
+    }
+
+    let items = field(lines, 1, "Starting items: ")?;
+    let items = on(
+        lines,
+        1,
+        items,
+        items
+            .split(", ")
+            .map(|item| parse_number(items, item))
+            .collect::<Result<Vec<i64>, _>>(),
+    )?;
+
+    let operation = field(lines, 2, "Operation: ")?;
+    let operation = on(lines, 2, operation, operation.parse())?;
+
+    let divisor = field(lines, 3, "Test: divisible by ")?;
+    let divisor = on(lines, 3, divisor, parse_number(divisor, divisor))?;
+
+    if divisor == 0 {
+        let (number, line) = lines[3];
+        let text = line
+            .trim_start()
+            .strip_prefix("Test: divisible by ")
+            .unwrap();
+
+        return Err(ParseError::new(line, text, "divisor can't be 0").on_line(number));
+    }
+
+    let target = |field_index, label| -> Result<usize, ParseError> {
+        let text = field(lines, field_index, label)?;
+        let target = on(lines, field_index, text, parse_number::<usize>(text, text))?;
+
+        if target >= count {
+            let (number, line) = lines[field_index];
+
+            return Err(ParseError::new(line, text, "unknown monkey").on_line(number));
+        }
+
+        Ok(target)
+    };
+
+    Ok(Monkey {
+        items,
+        operation,
+        divisor,
+        true_target: target(4, "If true: throw to monkey ")?,
+        false_target: target(5, "If false: throw to monkey ")?,
+    })
+}
+
+// monkeys are separated by blank lines and numbered from 0 in order
+pub fn parse_notes(input_string: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut blocks: Vec<Vec<(usize, &str)>> = vec![Vec::new()];
+
+    for (index, line) in input_string.lines().enumerate() {
+        if line.trim().is_empty() {
+            blocks.push(Vec::new());
+        } else {
+            blocks.last_mut().unwrap().push((index + 1, line));
+        }
+    }
+
+    blocks.retain(|block| !block.is_empty());
+
+    let count = blocks.len();
+
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(index, block)| parse_monkey(block, index, count))
+        .collect()
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// the least common multiple of the divisors, reducing worry levels modulo it doesn't change
+// any test
+pub fn modulus(monkeys: &[Monkey]) -> i64 {
+    monkeys.iter().fold(1, |modulus, monkey| {
+        modulus / gcd(modulus, monkey.divisor) * monkey.divisor
+    })
+}
+
+// a worry level, the operations only ever see non-negative levels and small constants
+pub trait Worry: Clone {
+    fn from_i64(value: i64) -> Self;
+
+    fn add(&self, other: &Self) -> Self;
+
+    fn mul(&self, other: &Self) -> Self;
+
+    fn div(&self, divisor: i64) -> Self;
+
+    fn rem(&self, divisor: i64) -> i64;
+}
+
+// panics on overflow, which happens within a few rounds without a policy that bounds the levels
+impl Worry for i64 {
+    fn from_i64(value: i64) -> Self {
+        value
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self.checked_add(*other).expect("worry level overflowed")
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.checked_mul(*other).expect("worry level overflowed")
+    }
+
+    fn div(&self, divisor: i64) -> Self {
+        self / divisor
+    }
+
+    fn rem(&self, divisor: i64) -> i64 {
+        self % divisor
+    }
+}
+
+// exact, for checking the shortcuts against
+impl Worry for BigUint {
+    fn from_i64(value: i64) -> Self {
+        BigUint::from(u64::try_from(value).expect("worry levels can't be negative"))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        BigUint::add(self, other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        BigUint::mul(self, other)
+    }
+
+    fn div(&self, divisor: i64) -> Self {
+        self.div_rem(digit(divisor)).0
+    }
+
+    fn rem(&self, divisor: i64) -> i64 {
+        self.div_rem(digit(divisor)).1.into()
+    }
+}
+
+fn digit(divisor: i64) -> u32 {
+    u32::try_from(divisor).expect("divisors have to fit in a u32")
+}
+
+// what happens to a worry level after the monkey has inspected the item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorryPolicy {
+    // relief that the item wasn't damaged, part1 divides by 3
+    Divide(i64),
+    // reduce modulo the LCM of the divisors, which keeps every test the same
+    Modulo,
+    // exact levels, these grow without bound so need a worry type that can't overflow
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct Simulation<W> {
+    // the monkeys without their items, which are kept in `items`
+    monkeys: Vec<Monkey>,
+    items: Vec<Vec<W>>,
+    policy: WorryPolicy,
+    modulus: i64,
+    inspections: Vec<i64>,
+    rounds: usize,
+}
+
+impl<W: Worry> Simulation<W> {
+    pub fn new(monkeys: &[Monkey], policy: WorryPolicy) -> Self {
+        Simulation {
+            monkeys: monkeys
+                .iter()
+                .map(|monkey| Monkey {
+                    items: Vec::new(),
+                    ..monkey.clone()
+                })
+                .collect(),
+            items: monkeys
+                .iter()
+                .map(|monkey| monkey.items.iter().map(|&item| W::from_i64(item)).collect())
+                .collect(),
+            policy,
+            modulus: modulus(monkeys),
+            inspections: vec![0; monkeys.len()],
+            rounds: 0,
+        }
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    // the items each monkey holds, in the order they will be inspected
+    pub fn items(&self) -> &[Vec<W>] {
+        &self.items
+    }
+
+    // how many items each monkey has inspected so far
+    pub fn inspections(&self) -> &[i64] {
+        &self.inspections
+    }
+
+    fn relieve(&self, level: W) -> W {
+        match self.policy {
+            WorryPolicy::Divide(divisor) => level.div(divisor),
+            WorryPolicy::Modulo => W::from_i64(level.rem(self.modulus)),
+            WorryPolicy::None => level,
+        }
+    }
+
+    pub fn round(&mut self) {
+        for index in 0..self.monkeys.len() {
+            let items = mem::take(&mut self.items[index]);
+            self.inspections[index] += items.len() as i64;
+
+            for item in items {
+                let monkey = &self.monkeys[index];
+                let level = self.relieve(monkey.operation.apply(&item));
+                let target = monkey.target(&level);
+
+                self.items[target].push(level);
+            }
+        }
+
+        self.rounds += 1;
+    }
+
+    pub fn run(&mut self, rounds: usize) {
+        for _ in 0..rounds {
+            self.round();
+        }
+    }
+
+    // runs `rounds` rounds, returning the inspection counts after each of them
+    pub fn snapshots(&mut self, rounds: usize) -> Vec<Vec<i64>> {
+        (0..rounds)
+            .map(|_| {
+                self.round();
+                self.inspections.clone()
+            })
+            .collect()
+    }
+
+    // the product of the `top` highest inspection counts
+    pub fn monkey_business(&self, top: usize) -> i64 {
+        monkey_business(&self.inspections, top)
+            .try_into()
+            .expect("monkey business overflowed")
+    }
+}
+
+pub fn simulate(monkeys: &[Monkey], policy: WorryPolicy, rounds: usize, top: usize) -> i64 {
+    let mut simulation = Simulation::<i64>::new(monkeys, policy);
+    simulation.run(rounds);
+
+    simulation.monkey_business(top)
+}
+
+// with the modulo policy an item only depends on which monkey holds it and its level, there
+// are finitely many of those so the rounds it goes through repeat sooner or later
+#[derive(Debug, Clone)]
+pub struct ItemCycle {
+    // the first round that is part of the cycle
+    start: usize,
+    length: usize,
+    // the inspections of the item by each monkey in its first r rounds, for r up to start + length
+    totals: Vec<Vec<i64>>,
+}
+
+impl ItemCycle {
+    pub fn find(monkeys: &[Monkey], modulus: i64, monkey: usize, level: i64) -> Self {
+        let mut seen = HashMap::new();
+        let mut totals = vec![vec![0; monkeys.len()]];
+        let mut state = (monkey, level);
+
+        loop {
+            if let Some(&start) = seen.get(&state) {
+                return ItemCycle {
+                    start,
+                    length: totals.len() - 1 - start,
+                    totals,
+                };
+            }
+
+            seen.insert(state, totals.len() - 1);
+
+            let mut counts = totals.last().unwrap().clone();
+            state = item_round(monkeys, modulus, state, &mut counts);
+            totals.push(counts);
+        }
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    // how many times each monkey inspects the item in the first `rounds` rounds
+    pub fn inspections(&self, rounds: u64) -> Vec<i64> {
+        let (start, length) = (self.start as u64, self.length as u64);
+
+        if rounds <= start + length {
+            return self.totals[rounds as usize].clone();
+        }
+
+        let cycles = ((rounds - start) / length) as i64;
+        let rest = ((rounds - start) % length) as usize;
+
+        let before = &self.totals[self.start];
+        let cycle = &self.totals[self.start + self.length];
+        let partial = &self.totals[self.start + rest];
+
+        (0..before.len())
+            .map(|index| partial[index] + cycles * (cycle[index] - before[index]))
+            .collect()
+    }
+}
+
+// moves one item through a round, it is inspected again in the same round whenever it is thrown
+// to a monkey that hasn't had its turn yet
+fn item_round(
+    monkeys: &[Monkey],
+    modulus: i64,
+    (mut monkey, mut level): (usize, i64),
+    counts: &mut [i64],
+) -> (usize, i64) {
+    loop {
+        counts[monkey] += 1;
+        level = monkeys[monkey].operation.apply(&level) % modulus;
+
+        let target = monkeys[monkey].target(&level);
+
+        if target <= monkey {
+            return (target, level);
+        }
+
+        monkey = target;
+    }
+}
+
+// the inspection counts after `rounds` rounds with the modulo policy, adding up each item's
+// cycle instead of playing every round
+pub fn fast_forward(monkeys: &[Monkey], rounds: u64) -> Vec<i64> {
+    let modulus = modulus(monkeys);
+    let mut inspections = vec![0; monkeys.len()];
+
+    for (index, monkey) in monkeys.iter().enumerate() {
+        for &item in &monkey.items {
+            let cycle = ItemCycle::find(monkeys, modulus, index, item % modulus);
+
+            for (total, count) in inspections.iter_mut().zip(cycle.inspections(rounds)) {
+                *total += count;
+            }
+        }
+    }
+
+    inspections
+}
+
+// the product of the `top` highest inspection counts, which soon needs more than an i64 when
+// fast forwarding
+pub fn monkey_business(inspections: &[i64], top: usize) -> i128 {
+    let mut inspections = inspections.to_vec();
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+
+    inspections
+        .iter()
+        .take(top)
+        .map(|&count| count as i128)
+        .product()
+}
+
+pub fn part1(monkeys: &[Monkey]) -> i64 {
+    simulate(monkeys, WorryPolicy::Divide(3), 20, 2)
+}
+
+pub fn part2(monkeys: &[Monkey]) -> i64 {
+    simulate(monkeys, WorryPolicy::Modulo, 10_000, 2)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_notes(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bignum::BigUint, fast_forward, modulus, monkey_business, parse_notes, part1, part2,
+        ItemCycle, Monkey, Operand, Operation, Simulation, Worry, WorryPolicy,
+    };
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day11/example.txt");
+
+    #[test]
+    fn test_parse_notes() {
+        let monkeys = parse_notes(TEST_INPUT).unwrap();
+
+        assert_eq!(monkeys.len(), 4);
+        assert_eq!(
+            monkeys[2],
+            Monkey {
+                items: vec![79, 60, 97],
+                operation: Operation::Multiply(Operand::Old),
+                divisor: 13,
+                true_target: 1,
+                false_target: 3,
+            }
+        );
+        assert_eq!(monkeys[1].operation, Operation::Add(Operand::Constant(6)));
+        assert_eq!(modulus(&monkeys), 23 * 19 * 13 * 17);
+
+        let error = parse_notes(&TEST_INPUT.replace("old + 6", "old - 6")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 10, column 24: unknown operator '-'"
+        );
+
+        let error = parse_notes(&TEST_INPUT.replace("monkey 0", "monkey 4")).unwrap_err();
+        assert_eq!(error.to_string(), "line 13, column 31: unknown monkey '4'");
+
+        let error = parse_notes(&TEST_INPUT.replace("Monkey 1:", "Monkey 7:")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 8, column 8: expected monkey 1 '7:'"
+        );
+
+        let error = parse_notes(&TEST_INPUT.replace("54, 65", "54, x5")).unwrap_err();
+        assert_eq!(error.to_string(), "line 9, column 23: invalid number 'x5'");
+    }
+
+    #[test]
+    fn test_operation() {
+        assert_eq!(Operation::Multiply(Operand::Old).apply(&7), 49);
+        assert_eq!("new = old * 19".parse::<Operation>().unwrap().apply(&2), 38);
+        assert_eq!("new = old + old".parse::<Operation>().unwrap().apply(&2), 4);
+    }
+
+    #[test]
+    fn test_simulation() {
+        let monkeys = parse_notes(TEST_INPUT).unwrap();
+
+        let mut simulation = Simulation::<i64>::new(&monkeys, WorryPolicy::Divide(3));
+        simulation.round();
+        assert_eq!(simulation.items()[0], vec![20, 23, 27, 26]);
+        assert_eq!(simulation.items()[1], vec![2080, 25, 167, 207, 401, 1046]);
+
+        simulation.run(19);
+        assert_eq!(simulation.rounds(), 20);
+        assert_eq!(simulation.inspections(), &[101, 95, 7, 105]);
+        assert_eq!(simulation.monkey_business(3), 105 * 101 * 95);
+        assert_eq!(simulation.monkey_business(10), 105 * 101 * 95 * 7);
+
+        let snapshots = Simulation::<i64>::new(&monkeys, WorryPolicy::Modulo).snapshots(20);
+        assert_eq!(snapshots.len(), 20);
+        assert_eq!(snapshots[0], vec![2, 4, 3, 6]);
+        assert_eq!(snapshots[19], vec![99, 97, 8, 103]);
+    }
+
+    // a small linear congruential generator, so the monkeys are the same every run
+    struct Random(u64);
+
+    impl Random {
+        fn below(&mut self, bound: usize) -> usize {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            (self.0 >> 33) as usize % bound
+        }
+    }
+
+    fn random_monkeys(random: &mut Random) -> Vec<Monkey> {
+        let count = 2 + random.below(5);
+        // squaring more than once a round makes the exact levels too big to check quickly
+        let squarer = random.below(count);
+
+        (0..count)
+            .map(|index| {
+                let constant = Operand::Constant(1 + random.below(9) as i64);
+                let operation = match random.below(4) {
+                    0 if index == squarer => Operation::Multiply(Operand::Old),
+                    0 => Operation::Add(Operand::Old),
+                    1 => Operation::Add(constant),
+                    _ => Operation::Multiply(constant),
+                };
+
+                // any monkey but itself
+                let targets = [
+                    (index + 1 + random.below(count - 1)) % count,
+                    (index + 1 + random.below(count - 1)) % count,
+                ];
+
+                Monkey {
+                    items: (0..1 + random.below(4))
+                        .map(|_| 1 + random.below(99) as i64)
+                        .collect(),
+                    operation,
+                    divisor: [2, 3, 5, 7, 11, 13, 17, 19, 23][random.below(9)],
+                    true_target: targets[0],
+                    false_target: targets[1],
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_exact_levels() {
+        let monkeys = parse_notes(TEST_INPUT).unwrap();
+
+        let mut exact = Simulation::<BigUint>::new(&monkeys, WorryPolicy::Divide(3));
+        exact.run(20);
+        assert_eq!(exact.inspections(), &[101, 95, 7, 105]);
+
+        // monkey 2 squares, so the exact levels soon pass what an i64 can hold
+        let mut exact = Simulation::<BigUint>::new(&monkeys, WorryPolicy::None);
+        exact.run(12);
+        assert!(exact
+            .items()
+            .iter()
+            .flatten()
+            .any(|level| level.bits() > 63));
+    }
+
+    // reducing modulo the LCM of the divisors sends every item to the same monkey as the exact
+    // levels do
+    #[test]
+    fn test_modulo_matches_exact() {
+        let mut random = Random(2022);
+
+        for _ in 0..200 {
+            let monkeys = random_monkeys(&mut random);
+            let modulus = modulus(&monkeys);
+
+            let mut exact = Simulation::<BigUint>::new(&monkeys, WorryPolicy::None);
+            let mut reduced = Simulation::<i64>::new(&monkeys, WorryPolicy::Modulo);
+
+            for _ in 0..8 {
+                exact.round();
+                reduced.round();
+
+                assert_eq!(exact.inspections(), reduced.inspections());
+
+                for (exact, reduced) in exact.items().iter().zip(reduced.items()) {
+                    let exact = exact
+                        .iter()
+                        .map(|level| level.rem(modulus))
+                        .collect::<Vec<_>>();
+
+                    assert_eq!(&exact, reduced, "{:?}", monkeys);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        let monkeys = parse_notes(TEST_INPUT).unwrap();
+        let mut simulation = Simulation::<i64>::new(&monkeys, WorryPolicy::Modulo);
+
+        for rounds in [1, 20, 1000, 10_000] {
+            simulation.run(rounds - simulation.rounds());
+
+            assert_eq!(
+                fast_forward(&monkeys, rounds as u64),
+                simulation.inspections()
+            );
+        }
+
+        // a full cycle adds the same inspections every time round
+        let cycle = ItemCycle::find(&monkeys, modulus(&monkeys), 0, 79);
+        assert!(cycle.length() > 0);
+        assert_eq!(
+            cycle.inspections((cycle.start() + cycle.length() * 3) as u64),
+            cycle
+                .inspections(cycle.start() as u64)
+                .iter()
+                .zip(cycle.inspections((cycle.start() + cycle.length()) as u64))
+                .map(|(before, after)| before + (after - before) * 3)
+                .collect::<Vec<_>>()
+        );
+
+        // every item is inspected at least once a round
+        let inspections = fast_forward(&monkeys, 1_000_000_000_000);
+        assert!(inspections.iter().sum::<i64>() >= 10 * 1_000_000_000_000);
+        assert!(monkey_business(&inspections, 2) > i64::MAX as i128);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_notes(TEST_INPUT).unwrap()), 10605);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_notes(TEST_INPUT).unwrap()), 2713310158);
+    }
+}
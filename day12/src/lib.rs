@@ -0,0 +1,166 @@
+use common::{
+    grid::{Grid, Point},
+    search::{self, Route},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Start,
+    End,
+    Path(i32),
+}
+
+impl Tile {
+    fn from_str(character: char) -> Tile {
+        match character {
+            'S' => Tile::Start,
+            'E' => Tile::End, // height = z
+            _ => Tile::Path(character as i32 - 'a' as i32),
+        }
+    }
+
+    fn height(self) -> i32 {
+        match self {
+            Tile::Start => 0,
+            Tile::End => 'z' as i32 - 'a' as i32,
+            Tile::Path(height) => height,
+        }
+    }
+
+    fn valid_next_step(self, neighbour: &Tile) -> bool {
+        neighbour.height() <= self.height() + 1
+    }
+}
+
+// the map needs exactly one of `marker`, a second one is reported where it is
+fn find_one(input_string: &str, marker: char) -> Result<(), ParseError> {
+    let mut found = input_string.lines().enumerate().flat_map(|(index, line)| {
+        line.match_indices(marker)
+            .map(move |(_, text)| ParseError::new(line, text, "more than one").on_line(index + 1))
+    });
+
+    match (found.next(), found.next()) {
+        (None, _) => Err(ParseError::new("", &marker.to_string(), "the map has no")),
+        (Some(_), Some(error)) => Err(error),
+        (Some(_), None) => Ok(()),
+    }
+}
+
+pub fn generate_matrix(input_string: &str) -> Result<Grid<Tile>, ParseError> {
+    let map = Grid::parse(input_string, |character| {
+        matches!(character, 'S' | 'E' | 'a'..='z').then(|| Tile::from_str(character))
+    })?;
+
+    find_one(input_string, 'S')?;
+    find_one(input_string, 'E')?;
+
+    Ok(map)
+}
+
+// the tiles that can be climbed to from `position`
+pub fn neighbours(map: &Grid<Tile>, position: Point) -> impl Iterator<Item = Point> + '_ {
+    map.neighbours4(position)
+        .filter(move |&neighbour| map[position].valid_next_step(&map[neighbour]))
+}
+
+// None if the end can't be climbed to
+fn shortest_route(
+    map: &Grid<Tile>,
+    starts: impl IntoIterator<Item = Point>,
+) -> Option<Route<Point, usize>> {
+    search::bfs(
+        starts,
+        |&position| neighbours(map, position),
+        |&position| map[position] == Tile::End,
+    )
+}
+
+pub fn part1(map: &Grid<Tile>) -> Option<i32> {
+    let start = map.position(|&tile| tile == Tile::Start)?;
+
+    shortest_route(map, [start]).map(|route| route.cost as i32)
+}
+
+pub fn part2(map: &Grid<Tile>) -> Option<i32> {
+    let lowest = map
+        .iter()
+        .filter(|(_, tile)| tile.height() == 0)
+        .map(|(position, _)| position);
+
+    shortest_route(map, lowest).map(|route| route.cost as i32)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<Tile>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        generate_matrix(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_matrix, neighbours, part1, part2};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day12/example.txt");
+
+    #[test]
+    fn test_neighbours() {
+        let map = generate_matrix(
+            "SabcE
+bbcdd",
+        )
+        .unwrap();
+
+        // from b back to a, on to c and down to c
+        assert_eq!(neighbours(&map, (2, 0)).count(), 3);
+        assert_eq!(
+            neighbours(&map, (0, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let map = generate_matrix("SabcdefghijklmnopqrstuvwxyzE").unwrap();
+
+        assert_eq!(part1(&map), Some(27));
+
+        // the end is too high to climb to
+        let map = generate_matrix("SazE").unwrap();
+        assert_eq!((part1(&map), part2(&map)), (None, None));
+    }
+
+    #[test]
+    fn test_generate_matrix() {
+        let error = generate_matrix("abc\nabE").unwrap_err();
+        assert_eq!(error.to_string(), "the map has no 'S'");
+
+        let error = generate_matrix("Sbc\nSbE").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: more than one 'S'");
+
+        let error = generate_matrix("SEc\nabE").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: more than one 'E'");
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&generate_matrix(TEST_INPUT).unwrap()), Some(31));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&generate_matrix(TEST_INPUT).unwrap()), Some(29));
+    }
+}
@@ -0,0 +1,200 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+use std::{cmp::Ordering, str::FromStr};
+
+use common::{parse::parse_number, Answer, ParseError, Solution};
+use pest::{iterators::Pair, Parser};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Signal(i32),
+}
+
+#[derive(Parser)]
+#[grammar = "grammar.pest"]
+pub struct InputParser;
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Packet::{List, Signal};
+
+        match (self, other) {
+            (Signal(sig1), Signal(sig2)) => sig1.cmp(sig2),
+            (List(packets1), List(packets2)) => {
+                let mut items1 = packets1.iter();
+                let mut items2 = packets2.iter();
+
+                loop {
+                    let next_item1 = items1.next();
+                    let next_item2 = items2.next();
+
+                    match (next_item1, next_item2) {
+                        (None, None) => return Ordering::Equal,
+                        (Some(_), None) => return Ordering::Greater,
+                        (None, Some(_)) => return Ordering::Less,
+                        (Some(val1), Some(val2)) => match val1.cmp(val2) {
+                            Ordering::Equal => continue,
+                            value => return value,
+                        },
+                    }
+                }
+            }
+            (list1, Packet::Signal(signal2)) => {
+                list1.cmp(&Packet::List(vec![Packet::Signal(*signal2)]))
+            }
+            (Packet::Signal(signal1), list2) => {
+                Packet::List(vec![Packet::Signal(*signal1)]).cmp(list2)
+            }
+        }
+    }
+}
+
+fn list_to_packet(line: &str, list: Pair<Rule>) -> Result<Vec<Packet>, ParseError> {
+    let mut packets: Vec<Packet> = vec![];
+
+    let pairs_list = list.into_inner();
+
+    for element in pairs_list {
+        match element.as_rule() {
+            Rule::number => packets.push(Packet::Signal(parse_number(line, element.as_str())?)),
+            Rule::list => packets.push(Packet::List(list_to_packet(line, element)?)),
+        }
+    }
+
+    Ok(packets)
+}
+
+// one packet on a line of its own
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let list = InputParser::parse(Rule::list, line)
+            .map_err(|error| {
+                let offset = match error.location {
+                    pest::error::InputLocation::Pos(offset) => offset,
+                    pest::error::InputLocation::Span((offset, _)) => offset,
+                };
+
+                ParseError::new(line, &line[offset..], "invalid packet")
+            })?
+            .next()
+            .unwrap();
+
+        let end = list.as_span().end();
+        if end < line.len() {
+            return Err(ParseError::new(
+                line,
+                &line[end..],
+                "unexpected text after the packet",
+            ));
+        }
+
+        Ok(Packet::List(list_to_packet(line, list)?))
+    }
+}
+
+// the blank lines between pairs are skipped
+pub fn parse_packets(input_string: &str) -> Result<Vec<Packet>, ParseError> {
+    input_string
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.parse::<Packet>()
+                .map_err(|error| error.on_line(index + 1))
+        })
+        .collect()
+}
+
+fn divider(value: i32) -> Packet {
+    Packet::List(vec![Packet::List(vec![Packet::Signal(value)])])
+}
+
+// the dividers are added unless the packets already have them
+pub fn part2(packets: &[Packet]) -> i32 {
+    let dividers = [divider(2), divider(6)];
+
+    let mut sorted = packets.iter().collect::<Vec<_>>();
+
+    for divider in &dividers {
+        if !packets.contains(divider) {
+            sorted.push(divider);
+        }
+    }
+
+    sorted.sort();
+
+    dividers
+        .iter()
+        .map(|divider| sorted.iter().position(|packet| *packet == divider).unwrap() as i32 + 1)
+        .product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Packet>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_packets(input_string)
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_packets, part2, Packet};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day13/example.txt");
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_packets(TEST_INPUT).unwrap()), 140);
+
+        // the same without the dividers and with blank lines between the pairs
+        let without_dividers = TEST_INPUT
+            .replace("[[2]]\n[[6]]", "")
+            .replace("]\n[", "]\n\n[");
+        assert_eq!(part2(&parse_packets(&without_dividers).unwrap()), 140);
+    }
+
+    #[test]
+    fn test_parse_packets() {
+        assert_eq!(
+            "[1,[]]".parse(),
+            Ok(Packet::List(vec![Packet::Signal(1), Packet::List(vec![])]))
+        );
+
+        let error = parse_packets("[1]\n[1,[2").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 5: invalid packet '2'");
+
+        let error = parse_packets("[1]\n\n[3]x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: unexpected text after the packet 'x'"
+        );
+
+        let error = parse_packets("[99999999999]").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 2: invalid number '99999999999'"
+        );
+    }
+}
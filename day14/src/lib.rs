@@ -0,0 +1,282 @@
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use common::{
+    grid::{Direction, Grid, Point},
+    parse::{self, parse_number},
+    Answer, ParseError, Solution,
+};
+
+type Position = (i32, i32);
+
+// the corners of a line of rock, every segment between them is horizontal or vertical
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RockPath(Vec<Position>);
+
+impl FromStr for RockPath {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut points: Vec<Position> = Vec::new();
+
+        for pair in line.split(" -> ") {
+            let (x, y) = pair
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(line, pair, "expected x,y"))?;
+            let point = (parse_number(line, x)?, parse_number(line, y)?);
+
+            if point.1 < 0 {
+                return Err(ParseError::new(line, y, "rock above the sand source"));
+            }
+
+            if let Some(&(x1, y1)) = points.last() {
+                if x1 != point.0 && y1 != point.1 {
+                    return Err(ParseError::new(line, pair, "diagonal rock"));
+                }
+            }
+
+            points.push(point);
+        }
+
+        Ok(RockPath(points))
+    }
+}
+
+impl RockPath {
+    pub fn rocks(&self) -> HashSet<Position> {
+        let mut result: HashSet<Position> = HashSet::new();
+
+        self.0.windows(2).for_each(|window| {
+            let (x1, y1) = window[0];
+            let (x2, y2) = window[1];
+
+            if x1 == x2 {
+                if y1 >= y2 {
+                    result.extend((y2..=y1).map(|y| (x1, y)));
+                } else {
+                    result.extend((y1..=y2).map(|y| (x1, y)));
+                }
+            } else {
+                if x1 >= x2 {
+                    result.extend((x2..=x1).map(|x| (x, y1)));
+                } else {
+                    result.extend((x1..=x2).map(|x| (x, y1)));
+                }
+            }
+        });
+
+        result
+    }
+}
+
+pub fn parse_line(line_input: &str) -> Result<HashSet<Position>, ParseError> {
+    Ok(line_input.parse::<RockPath>()?.rocks())
+}
+
+pub fn parse_lines(lines_input: &str) -> Result<HashSet<Position>, ParseError> {
+    let paths = parse::parse_lines::<RockPath>(lines_input, 1)?;
+
+    let mut final_result = HashSet::new();
+
+    for path in paths {
+        final_result.extend(path.rocks());
+    }
+
+    Ok(final_result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Air => write!(f, "."),
+            Cell::Rock => write!(f, "#"),
+            Cell::Sand => write!(f, "o"),
+        }
+    }
+}
+
+const SOURCE: Position = (500, 0);
+
+// everything above the floor that sand from SOURCE can reach, sand spreads at most one
+// step sideways for every step down so the cave is a triangle
+struct Cave {
+    cells: Grid<Cell>,
+    // x of the leftmost column
+    left: i32,
+}
+
+impl Cave {
+    fn new(walls: &HashSet<Position>, floor_height: i32) -> Cave {
+        let mut cave = Cave {
+            cells: Grid::new(
+                2 * floor_height as usize + 1,
+                floor_height as usize,
+                Cell::Air,
+            ),
+            left: SOURCE.0 - floor_height,
+        };
+
+        for &wall in walls {
+            // walls outside the triangle can't be hit
+            if let Some(cell) = cave.point(wall).and_then(|point| cave.cells.get_mut(point)) {
+                *cell = Cell::Rock;
+            }
+        }
+
+        cave
+    }
+
+    fn point(&self, (x, y): Position) -> Option<Point> {
+        Some((
+            usize::try_from(x - self.left).ok()?,
+            usize::try_from(y).ok()?,
+        ))
+    }
+}
+
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+// the floor is just below the last row, so stepping out of the grid means hitting it
+fn valid_steps(point: Point, cave: &Cave) -> Option<Point> {
+    let prioritised_step_deltas: [Direction; 3] = [(0, 1), (-1, 1), (1, 1)];
+
+    prioritised_step_deltas
+        .iter()
+        .filter_map(|&delta| cave.cells.step(point, delta))
+        .find(|&proposed_point| cave.cells[proposed_point] == Cell::Air)
+}
+
+fn dfs(point: Point, source: Point, cave: &mut Cave) -> bool {
+    while let Some(valid_move) = valid_steps(point, cave) {
+        if dfs(valid_move, source, cave) {
+            return true;
+        }
+    }
+
+    cave.cells[point] = Cell::Sand;
+
+    point == source
+}
+
+pub fn part2(walls: &HashSet<Position>) -> i32 {
+    // Lowest wall is start of void
+    let floor_start_y_pos = walls.iter().map(|&(_, y)| y).max().unwrap_or(0) + 2;
+
+    let mut cave = Cave::new(walls, floor_start_y_pos);
+    let source = cave.point(SOURCE).unwrap();
+
+    dfs(source, source, &mut cave);
+
+    cave.cells
+        .iter()
+        .filter(|(_, &cell)| cell == Cell::Sand)
+        .count() as i32
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = HashSet<Position>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input_string)
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{dfs, parse_line, parse_lines, part2, Cave, Position, SOURCE};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day14/example.txt");
+
+    #[test]
+    fn test_parse_line() {
+        let mut expected: HashSet<Position> = HashSet::new();
+
+        expected.insert((498, 4));
+        expected.insert((498, 5));
+        expected.insert((498, 6));
+        expected.insert((497, 6));
+        expected.insert((496, 6));
+
+        assert_eq!(parse_line("498,4 -> 498,6 -> 496,6"), Ok(expected));
+
+        let error = parse_line("498,4 -> 49x,6").unwrap_err();
+        assert_eq!(error.to_string(), "invalid number '49x'");
+        assert_eq!(error.column, 10);
+
+        let error = parse_lines("498,4 -> 498,6\n1,1 -> 2,2").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 8: diagonal rock '2,2'");
+
+        let error = parse_lines("1,1 -> 1;2").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 8: expected x,y '1;2'");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let mut expected: HashSet<Position> = HashSet::new();
+
+        expected.insert((498, 4));
+        expected.insert((498, 5));
+        expected.insert((498, 6));
+        expected.insert((497, 6));
+        expected.insert((496, 6));
+        expected.insert((1, 1));
+
+        assert_eq!(
+            parse_lines(
+                "498,4 -> 498,6 -> 496,6
+1,1 -> 1,1 -> 1,1"
+            ),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_lines(TEST_INPUT).unwrap()), 93);
+    }
+
+    #[test]
+    fn test_cave() {
+        let mut cave = Cave::new(&parse_lines(TEST_INPUT).unwrap(), 11);
+        let source = cave.point(SOURCE).unwrap();
+
+        dfs(source, source, &mut cave);
+
+        assert_eq!(
+            cave.to_string(),
+            "...........o...........
+..........ooo..........
+.........ooooo.........
+........ooooooo........
+.......oo#ooo##o.......
+......ooo#ooo#ooo......
+.....oo###ooo#oooo.....
+....oooo.oooo#ooooo....
+...oooooooooo#oooooo...
+..ooo#########ooooooo..
+.ooooo.......ooooooooo."
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use common::{parse::parse_number, Answer, ParseError, Solution};
+
+type Position = (i32, i32);
+
+fn manhattan_distance((x1, y1): Position, (x2, y2): Position) -> i32 {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    pub sensor_position: Position,
+    pub closest_beacon_position: Position,
+}
+
+impl Sensor {
+    pub fn from_tuples(positions: (Position, Position)) -> Self {
+        Self {
+            sensor_position: positions.0,
+            closest_beacon_position: positions.1,
+        }
+    }
+
+    fn sensor_radius(&self) -> i32 {
+        manhattan_distance(self.sensor_position, self.closest_beacon_position)
+    }
+
+    fn distance_to(&self, to: &Position) -> i32 {
+        manhattan_distance(self.sensor_position, *to)
+    }
+
+    pub fn span_for_y(&self, y: i32) -> RangeInclusive<i32> {
+        let distance_from_center = (self.sensor_position.1 - y).abs();
+
+        let side_width = self.sensor_radius() - distance_from_center;
+
+        (self.sensor_position.0 - side_width)..=(self.sensor_position.0 + side_width)
+    }
+
+    pub fn rightmost_point_for_y(&self, y: i32) -> i32 {
+        let distance_from_center = (self.sensor_position.1 - y).abs();
+
+        let side_width = self.sensor_radius() - distance_from_center;
+
+        self.sensor_position.0 + side_width
+    }
+}
+
+fn parse_position(line: &str, position: &str) -> Result<Position, ParseError> {
+    let (x, y) = position
+        .split_once(", ")
+        .ok_or_else(|| ParseError::new(line, position, "expected a position like x=2, y=18"))?;
+
+    Ok((
+        parse_number(line, x.trim_start_matches("x="))?,
+        parse_number(line, y.trim_start_matches("y="))?,
+    ))
+}
+
+pub fn parse_report(input_string: &str) -> Result<Vec<(Position, Position)>, ParseError> {
+    input_string
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (sensor, beacon) = line
+                .trim_start_matches("Sensor at ")
+                .split_once(": closest beacon is at ")
+                .ok_or_else(|| ParseError::new(line, line, "expected a sensor and a beacon"))
+                .map_err(|error| error.on_line(index + 1))?;
+
+            Ok((
+                parse_position(line, sensor).map_err(|error| error.on_line(index + 1))?,
+                parse_position(line, beacon).map_err(|error| error.on_line(index + 1))?,
+            ))
+        })
+        .collect()
+}
+
+// --------------------------------------------------------------------------------
+
+pub fn part1(input: &[(Position, Position)], y: i32) -> i32 {
+    let sensors = input.iter().copied().map(Sensor::from_tuples);
+
+    let mut positions: HashSet<i32> = HashSet::new();
+
+    for sensor in sensors {
+        for x in sensor.span_for_y(y) {
+            if x == sensor.closest_beacon_position.0 && y == sensor.closest_beacon_position.1 {
+                continue;
+            }
+
+            positions.insert(x);
+        }
+    }
+
+    positions.len() as i32
+}
+
+pub fn part2(input: &[(Position, Position)], max_xy: i32) -> i64 {
+    let factor: i64 = 4_000_000;
+
+    let sensors: Vec<Sensor> = input.iter().copied().map(Sensor::from_tuples).collect();
+
+    let radii = sensors
+        .iter()
+        .map(|sensor| sensor.sensor_radius())
+        .collect::<Vec<_>>();
+
+    let mut pos = (0, 0);
+
+    while let Some(in_range_of) = sensors
+        .iter()
+        .enumerate()
+        .find(|(index, sensor)| sensor.distance_to(&pos) <= radii[*index])
+    {
+        let new_x = in_range_of.1.rightmost_point_for_y(pos.1) + 1;
+
+        if new_x > max_xy {
+            pos = (0, pos.1 + 1);
+        } else {
+            pos.0 = new_x;
+        }
+    }
+
+    (pos.0 as i64) * factor + (pos.1 as i64)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<(Position, Position)>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_report(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input, 2000000).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input, 4000000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_report, part1, part2, Sensor};
+
+    // the example has its own row and search area, so it isn't a fixture for the runner
+    const TEST_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_span() {
+        assert_eq!(
+            Sensor::from_tuples(((8, 7), (2, 10))).span_for_y(14),
+            6..=10
+        );
+    }
+
+    #[test]
+    fn test_parse_report() {
+        assert_eq!(
+            parse_report(
+                "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16"
+            ),
+            Ok(vec![((2, 18), (-2, 15)), ((9, 16), (10, 16))])
+        );
+
+        let error =
+            parse_report("Sensor at x=2, y=1b: closest beacon is at x=-2, y=15").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 18: invalid number '1b'");
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_report(TEST_INPUT).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_report(TEST_INPUT).unwrap(), 20), 56000011);
+    }
+}
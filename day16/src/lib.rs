@@ -0,0 +1,615 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+    str::FromStr,
+};
+
+use common::{
+    graph::{Distances, Graph, NodeId},
+    parse::parse_number,
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valve {
+    pub name: String,
+    pub flow_rate: i32,
+    pub tunnels_lead_to: Vec<String>,
+}
+
+const EXPECTED_VALVE: &str =
+    "expected 'Valve <name> has flow rate=<rate>; tunnels lead to valves <names>'";
+
+// name, flow rate and tunnels of a line, all slices of it so errors can point into the line
+fn split_line(line: &str) -> Result<(&str, &str, Vec<&str>), ParseError> {
+    let (name, rest) = line
+        .strip_prefix("Valve ")
+        .and_then(|rest| rest.split_once(" has flow rate="))
+        .ok_or_else(|| ParseError::new(line, line, EXPECTED_VALVE))?;
+
+    let (flow_rate, tunnels) = rest
+        .split_once("; ")
+        .ok_or_else(|| ParseError::new(line, rest, EXPECTED_VALVE))?;
+
+    // a valve with a single tunnel is written in the singular
+    let tunnels_lead_to = tunnels
+        .strip_prefix("tunnels lead to valves ")
+        .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+        .ok_or_else(|| {
+            ParseError::new(
+                line,
+                tunnels,
+                "expected 'tunnels lead to valves' or 'tunnel leads to valve'",
+            )
+        })?;
+
+    Ok((name, flow_rate, tunnels_lead_to.split(", ").collect()))
+}
+
+fn valve(
+    line: &str,
+    name: &str,
+    flow_rate: &str,
+    tunnels_lead_to: &[&str],
+) -> Result<Valve, ParseError> {
+    Ok(Valve {
+        name: name.to_owned(),
+        flow_rate: parse_number(line, flow_rate)?,
+        tunnels_lead_to: tunnels_lead_to.iter().map(|&to| to.to_owned()).collect(),
+    })
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (name, flow_rate, tunnels_lead_to) = split_line(line)?;
+
+        valve(line, name, flow_rate, &tunnels_lead_to)
+    }
+}
+
+// every valve has to be named once, and tunnels can only lead to named valves
+pub fn parse_scan(input_string: &str) -> Result<Vec<Valve>, ParseError> {
+    let mut valves = Vec::new();
+    let mut names = HashSet::new();
+    // the line number, line and tunnels of every valve, for checking the tunnels once every
+    // valve is known
+    let mut tunnels = Vec::new();
+
+    for (index, line) in input_string.lines().enumerate() {
+        let on_line = |error: ParseError| error.on_line(index + 1);
+        let (name, flow_rate, tunnels_lead_to) = split_line(line).map_err(on_line)?;
+
+        if !names.insert(name) {
+            return Err(on_line(ParseError::new(line, name, "duplicate valve")));
+        }
+
+        valves.push(valve(line, name, flow_rate, &tunnels_lead_to).map_err(on_line)?);
+        tunnels.push((index + 1, line, tunnels_lead_to));
+    }
+
+    for (line_number, line, tunnels_lead_to) in tunnels {
+        if let Some(unknown) = tunnels_lead_to.into_iter().find(|to| !names.contains(to)) {
+            return Err(ParseError::new(line, unknown, "unknown valve").on_line(line_number));
+        }
+    }
+
+    Ok(valves)
+}
+
+// the valves are kept as bitmasks, so there can only be so many of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyValves {
+    pub valves: usize,
+    pub limit: usize,
+}
+
+impl fmt::Display for TooManyValves {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} valves, at most {} are supported",
+            self.valves, self.limit
+        )
+    }
+}
+
+impl Error for TooManyValves {}
+
+// the start and the valves worth opening, with the minutes it takes to walk between them
+pub struct Tunnels {
+    pub names: Vec<String>,
+    pub flow_rates: Vec<i32>,
+    pub distances: Distances,
+    pub start: NodeId,
+}
+
+impl Tunnels {
+    pub fn new(valves: &[Valve], start: &str) -> Result<Tunnels, TooManyValves> {
+        let mut graph = Graph::new();
+        graph.intern(start.to_owned());
+
+        // ids in the order of the scan
+        for valve in valves {
+            graph.intern(valve.name.clone());
+        }
+
+        for valve in valves {
+            for leads_to in &valve.tunnels_lead_to {
+                graph.add_edge(valve.name.clone(), leads_to.clone(), 1);
+            }
+        }
+
+        let flow_rates = valves
+            .iter()
+            .map(|valve| (valve.name.as_str(), valve.flow_rate))
+            .collect::<HashMap<_, _>>();
+        let flow_rate = |name: &String| flow_rates.get(name.as_str()).copied().unwrap_or(0);
+
+        // every tunnel takes a minute, so a bfs from each valve is enough
+        let compressed = graph.compress(&graph.bfs_distances(), |name| {
+            name == start || flow_rate(name) > 0
+        });
+
+        // the opened valves are kept as a bitmask over the node ids
+        if compressed.len() > 64 {
+            return Err(TooManyValves {
+                valves: compressed.len(),
+                limit: 64,
+            });
+        }
+
+        Ok(Tunnels {
+            names: compressed.nodes().to_vec(),
+            flow_rates: compressed.nodes().iter().map(flow_rate).collect(),
+            distances: compressed.floyd_warshall(),
+            start: compressed.id(&start.to_owned()).unwrap(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opening {
+    // the minute spent opening the valve, it releases pressure from the next one on
+    pub minute: i32,
+    pub valve: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub pressure: i32,
+    pub openings: Vec<Opening>,
+}
+
+// (position, minutes left, opened valves) to the most pressure that can still be released
+type Memo = HashMap<(NodeId, i32, u64), i32>;
+
+impl Tunnels {
+    // every valve that is worth opening
+    pub fn all_valves(&self) -> u64 {
+        (0..self.names.len())
+            .filter(|&valve| self.flow_rates[valve] > 0)
+            .fold(0, |valves, valve| valves | 1 << valve)
+    }
+
+    // the valve can be reached and opened with time to spare, and how many minutes are left then
+    fn open(&self, position: NodeId, minutes_left: i32, valve: NodeId) -> Option<i32> {
+        let distance = self.distances.get(position, valve)? as i32;
+        let minutes_left = minutes_left - distance - 1;
+
+        (minutes_left > 0).then_some(minutes_left)
+    }
+
+    fn release(
+        &self,
+        position: NodeId,
+        minutes_left: i32,
+        opened: u64,
+        allowed: u64,
+        memo: &mut Memo,
+    ) -> i32 {
+        if let Some(&pressure) = memo.get(&(position, minutes_left, opened)) {
+            return pressure;
+        }
+
+        let mut best = 0;
+
+        for valve in (0..self.names.len()).filter(|valve| allowed & !opened & 1 << valve != 0) {
+            if let Some(left) = self.open(position, minutes_left, valve) {
+                let pressure = self.flow_rates[valve] * left
+                    + self.release(valve, left, opened | 1 << valve, allowed, memo);
+
+                best = best.max(pressure);
+            }
+        }
+
+        memo.insert((position, minutes_left, opened), best);
+
+        best
+    }
+
+    // the most pressure one walker can release from the start by opening valves in `allowed`
+    pub fn best_plan(&self, minutes: i32, allowed: u64) -> Plan {
+        let mut memo = Memo::new();
+        let pressure = self.release(self.start, minutes, 0, allowed, &mut memo);
+
+        // walk the memo again, taking an opening that leads to the best result every time
+        let mut openings = Vec::new();
+        let (mut position, mut minutes_left, mut opened, mut remaining) =
+            (self.start, minutes, 0, pressure);
+
+        while remaining > 0 {
+            let (valve, left) = (0..self.names.len())
+                .filter(|valve| allowed & !opened & 1 << valve != 0)
+                .filter_map(|valve| Some((valve, self.open(position, minutes_left, valve)?)))
+                .find(|&(valve, left)| {
+                    self.flow_rates[valve] * left
+                        + self.release(valve, left, opened | 1 << valve, allowed, &mut memo)
+                        == remaining
+                })
+                .unwrap();
+
+            openings.push(Opening {
+                minute: minutes - left,
+                valve: self.names[valve].clone(),
+            });
+
+            remaining -= self.flow_rates[valve] * left;
+            (position, minutes_left, opened) = (valve, left, opened | 1 << valve);
+        }
+
+        Plan { pressure, openings }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Team {
+    pub pressure: i32,
+    // one plan per agent, no two of them open the same valve
+    pub plans: Vec<Plan>,
+}
+
+impl Tunnels {
+    // the most pressure released by opening exactly the valves in each set, the sets are
+    // bitmasks over `valves` rather than over node ids
+    fn record(
+        &self,
+        valves: &[NodeId],
+        (position, minutes_left, opened): (NodeId, i32, usize),
+        pressure: i32,
+        best: &mut [i32],
+    ) {
+        best[opened] = best[opened].max(pressure);
+
+        for (bit, &valve) in valves.iter().enumerate() {
+            if opened & 1 << bit != 0 {
+                continue;
+            }
+
+            if let Some(left) = self.open(position, minutes_left, valve) {
+                self.record(
+                    valves,
+                    (valve, left, opened | 1 << bit),
+                    pressure + self.flow_rates[valve] * left,
+                    best,
+                );
+            }
+        }
+    }
+
+    // `agents` walkers leaving the start together with `minutes` each, the valves are split
+    // into one disjoint share per agent and each agent plans its own share. every share is
+    // tabulated, so only up to 20 valves with flow can be split between two or more agents
+    pub fn best_team(&self, agents: usize, minutes: i32) -> Result<Team, TooManyValves> {
+        match agents {
+            0 => {
+                return Ok(Team {
+                    pressure: 0,
+                    plans: Vec::new(),
+                })
+            }
+            // nothing to split
+            1 => {
+                let plan = self.best_plan(minutes, self.all_valves());
+
+                return Ok(Team {
+                    pressure: plan.pressure,
+                    plans: vec![plan],
+                });
+            }
+            _ => {}
+        }
+
+        let valves = (0..self.names.len())
+            .filter(|&valve| self.flow_rates[valve] > 0)
+            .collect::<Vec<_>>();
+
+        if valves.len() > 20 {
+            return Err(TooManyValves {
+                valves: valves.len(),
+                limit: 20,
+            });
+        }
+
+        let size = 1 << valves.len();
+
+        // best[share] ends up as the most one agent can release opening only valves in share
+        let mut best = vec![0; size];
+        self.record(&valves, (self.start, minutes, 0), 0, &mut best);
+
+        for bit in 0..valves.len() {
+            for share in 0..size {
+                if share & 1 << bit != 0 {
+                    best[share] = best[share].max(best[share ^ 1 << bit]);
+                }
+            }
+        }
+
+        // team[valves] is the most k agents release between them, shares[k - 1][valves] the
+        // share of the k-th agent, a single agent takes everything
+        let mut team = best.clone();
+        let mut shares = vec![(0..size).collect::<Vec<_>>()];
+
+        for _ in 1..agents {
+            let mut next_team = vec![0; size];
+            let mut next_shares = vec![0; size];
+
+            for available in 0..size {
+                // every subset of the available valves, from the full set down to empty
+                let mut share = available;
+
+                loop {
+                    let pressure = best[share] + team[available ^ share];
+
+                    if pressure > next_team[available] {
+                        next_team[available] = pressure;
+                        next_shares[available] = share;
+                    }
+
+                    if share == 0 {
+                        break;
+                    }
+
+                    share = (share - 1) & available;
+                }
+            }
+
+            team = next_team;
+            shares.push(next_shares);
+        }
+
+        let mut available = size - 1;
+        let mut plans = Vec::new();
+
+        for agent_shares in shares.iter().rev() {
+            let share = agent_shares[available];
+            available ^= share;
+
+            let allowed = valves
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| share & 1 << bit != 0)
+                .fold(0, |allowed, (_, &valve)| allowed | 1 << valve);
+
+            plans.push(self.best_plan(minutes, allowed));
+        }
+
+        Ok(Team {
+            pressure: team[size - 1],
+            plans,
+        })
+    }
+}
+
+pub fn part1(valves: &[Valve]) -> Result<i32, TooManyValves> {
+    let tunnels = Tunnels::new(valves, "AA")?;
+
+    Ok(tunnels.best_plan(30, tunnels.all_valves()).pressure)
+}
+
+// you and an elephant, after spending four minutes teaching it
+pub fn part2(valves: &[Valve]) -> Result<i32, TooManyValves> {
+    Ok(Tunnels::new(valves, "AA")?.best_team(2, 26)?.pressure)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<Valve>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_scan(input_string)
+    }
+
+    // scans with more valves than the bitmasks hold are left unsolved
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use crate::{parse_scan, part1, part2, TooManyValves, Tunnels, Valve};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day16/example.txt");
+
+    fn example_valves() -> Vec<Valve> {
+        parse_scan(TEST_INPUT).unwrap()
+    }
+
+    #[test]
+    fn test_parse_scan() {
+        let valves = example_valves();
+
+        assert_eq!(valves.len(), 10);
+        assert_eq!(
+            valves[7],
+            Valve {
+                name: "HH".to_owned(),
+                flow_rate: 22,
+                tunnels_lead_to: vec!["GG".to_owned()],
+            }
+        );
+        assert_eq!(
+            valves[3].tunnels_lead_to,
+            vec!["CC".to_owned(), "AA".to_owned(), "EE".to_owned()]
+        );
+
+        assert_eq!(
+            parse_scan("Valve AA has flow rate=0; tunnels lead to valves BB, CC\nValve BB has flow rate=3; tunnel leads to valve AA")
+                .unwrap_err(),
+            ParseError {
+                line: 1,
+                column: 54,
+                text: "CC".to_owned(),
+                message: "unknown valve".to_owned(),
+            }
+        );
+
+        let error = parse_scan("Valve AA has flow rate=0; tunnel leads to valve AA\nValve AA has flow rate=1; tunnel leads to valve AA")
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 7: duplicate valve 'AA'");
+
+        let error = parse_scan("Valve AA has flow rate=x; tunnel leads to valve AA").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 24: invalid number 'x'");
+
+        let error = parse_scan("Valve AA has flow rate=0; tunnels go to AA").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 27: expected 'tunnels lead to valves' or 'tunnel leads to valve' 'tunnels go to AA'"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&example_valves()), Ok(1651));
+    }
+
+    #[test]
+    fn test_best_plan() {
+        let tunnels = Tunnels::new(&example_valves(), "AA").unwrap();
+        let plan = tunnels.best_plan(30, tunnels.all_valves());
+
+        let openings = plan
+            .openings
+            .iter()
+            .map(|opening| (opening.minute, opening.valve.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(plan.pressure, 1651);
+        assert_eq!(
+            openings,
+            vec![
+                (2, "DD"),
+                (5, "BB"),
+                (9, "JJ"),
+                (17, "HH"),
+                (21, "EE"),
+                (24, "CC")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tunnels() {
+        let tunnels = Tunnels::new(&example_valves(), "AA").unwrap();
+
+        assert_eq!(
+            tunnels.names,
+            vec!["AA", "BB", "CC", "DD", "EE", "HH", "JJ"]
+        );
+        assert_eq!(tunnels.flow_rates, vec![0, 13, 2, 20, 3, 22, 21]);
+        assert_eq!(tunnels.start, 0);
+
+        let distance = |from: &str, to: &str| {
+            let id = |name: &str| tunnels.names.iter().position(|n| n == name).unwrap();
+
+            tunnels.distances.get(id(from), id(to))
+        };
+
+        assert_eq!(distance("AA", "DD"), Some(1));
+        assert_eq!(distance("AA", "HH"), Some(5));
+        assert_eq!(distance("HH", "JJ"), Some(7));
+        assert_eq!(distance("JJ", "JJ"), Some(0));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&example_valves()), Ok(1707));
+    }
+
+    #[test]
+    fn test_best_team() {
+        let tunnels = Tunnels::new(&example_valves(), "AA").unwrap();
+
+        let routes = |agents, minutes| {
+            let team = tunnels.best_team(agents, minutes).unwrap();
+
+            assert_eq!(
+                team.plans.iter().map(|plan| plan.pressure).sum::<i32>(),
+                team.pressure
+            );
+
+            let routes = team
+                .plans
+                .iter()
+                .map(|plan| {
+                    plan.openings
+                        .iter()
+                        .map(|opening| opening.valve.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect::<Vec<_>>();
+
+            (team.pressure, routes)
+        };
+
+        assert_eq!(routes(0, 26), (0, vec![]));
+        assert_eq!(routes(1, 30), (1651, vec!["DD,BB,JJ,HH,EE,CC".to_owned()]));
+        assert_eq!(
+            routes(2, 26),
+            (1707, vec!["JJ,BB,CC".to_owned(), "DD,HH,EE".to_owned()])
+        );
+        assert_eq!(
+            routes(3, 26),
+            (
+                1794,
+                vec!["JJ".to_owned(), "DD,HH".to_owned(), "BB,CC,EE".to_owned()]
+            )
+        );
+
+        // a line of valves, too many to split but fine for a single walker
+        let valves = (0..21)
+            .map(|index| Valve {
+                name: format!("V{}", index),
+                flow_rate: 1,
+                tunnels_lead_to: vec![format!("V{}", (index + 1) % 21)],
+            })
+            .collect::<Vec<_>>();
+        let tunnels = Tunnels::new(&valves, "V0").unwrap();
+
+        assert!(tunnels.best_team(0, 26).is_ok());
+        // open a valve every other minute going round, 9 + 7 + 5 + 3 + 1
+        assert_eq!(tunnels.best_team(1, 10).unwrap().pressure, 25);
+        assert_eq!(
+            tunnels.best_team(2, 26).unwrap_err().to_string(),
+            "21 valves, at most 20 are supported"
+        );
+        assert_eq!(
+            part2(&valves),
+            Err(TooManyValves {
+                valves: 21,
+                limit: 20
+            })
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use common::{Answer, ParseError, Solution};
+
+/*
+ * Prøvde først en klassisk brute-force i håp om at de hadde vært snille og gitt en løsning uten overlappende ord.
+ * Det hadde de naturligvis ikke så da ble det denne løsningen. Løs for ord, og sjekk om resterende streng er gyldig.
+ * Dersom den ikke er det tar vi et lengre ord.
+ */
+
+pub fn solve_recursively(
+    dictionary: &HashMap<String, String>,
+    input: String,
+) -> Option<Vec<String>> {
+    let mut buffer = String::new();
+    let mut input_clone = input.clone();
+
+    if input_clone.is_empty() {
+        return Some(vec![]);
+    }
+
+    loop {
+        if input_clone.is_empty() {
+            return None;
+        }
+
+        buffer += &input_clone[0..1];
+        input_clone = input_clone[1..].to_string();
+
+        // Løs for ord
+        let Some(value) = dictionary.get(buffer.as_str()) else {
+            continue;
+        };
+
+        // Løs for resterende ord
+        let Some(rest_solution) = solve_recursively(dictionary, input_clone.clone()) else {
+            continue;
+        };
+
+        let mut new_result = vec![value.to_string()];
+        new_result.extend(rest_solution);
+
+        return Some(new_result);
+    }
+}
+
+// one "<word>,<translation>" per line
+pub fn parse_dictionary(input_string: &str) -> Result<HashMap<String, String>, ParseError> {
+    input_string
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (key, value) = line.split_once(',').ok_or_else(|| {
+                ParseError::new(line, line, "expected '<word>,<translation>'").on_line(index + 1)
+            })?;
+
+            Ok((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+pub struct Letter {
+    pub dictionary: HashMap<String, String>,
+    pub text: String,
+}
+
+// the puzzle comes as two files, as one input they are the dictionary, a blank line and the
+// letter
+pub fn parse_letter(input_string: &str) -> Result<Letter, ParseError> {
+    let (dictionary_string, text) = input_string.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            input_string,
+            "",
+            "expected a blank line after the dictionary",
+        )
+        .on_line(1)
+    })?;
+
+    Ok(Letter {
+        dictionary: parse_dictionary(dictionary_string)?,
+        text: text.trim_end().to_string(),
+    })
+}
+
+// the length of the translated letter with a space between the words
+pub fn part1(letter: &Letter) -> Option<usize> {
+    let words = solve_recursively(&letter.dictionary, letter.text.clone())?;
+
+    Some(words.join(" ").len())
+}
+
+// not part of the aoc runner, its days are numbered by the advent of code calendar and this
+// puzzle isn't in it
+pub struct Day1Knowit;
+
+impl Solution for Day1Knowit {
+    type Input = Letter;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_letter(input_string)
+    }
+
+    // a letter that can't be split into words has no answer
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    // there is only one part
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_dictionary, parse_letter, part1};
+
+    #[test]
+    fn test_part1() {
+        let letter = parse_letter("ini,I\nake,his\nsezvo,as\n\niniakesezvo\n").unwrap();
+        assert_eq!(part1(&letter), Some("I his as".len()));
+
+        let letter = parse_letter("ini,I\n\niniake").unwrap();
+        assert_eq!(part1(&letter), None);
+    }
+
+    #[test]
+    fn test_parse_dictionary() {
+        let error = parse_dictionary("ini,I\nake his").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected '<word>,<translation>' 'ake his'"
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::{play_shapes, Column, Game, Round, Scoring, Shape};
+
+// what X, Y and Z mean when the second column is a shape
+pub type Mapping = [Shape; 3];
+
+// every way of giving X, Y and Z a different shape of a three shape game
+pub fn mappings(game: &Game) -> Vec<Mapping> {
+    let mut mappings = Vec::new();
+
+    for x in game.shapes() {
+        for y in game.shapes().filter(|&y| y != x) {
+            for z in game.shapes().filter(|&z| z != x && z != y) {
+                mappings.push([x, y, z]);
+            }
+        }
+    }
+
+    mappings
+}
+
+// how often each shape was played, as fractions of the rounds
+pub fn distribution<I>(game: &Game, shapes: I) -> Vec<f64>
+where
+    I: IntoIterator<Item = Shape>,
+{
+    let mut counts = vec![0.0; game.len()];
+    let mut total = 0.0;
+
+    for shape in shapes {
+        counts[shape.0] += 1.0;
+        total += 1.0;
+    }
+
+    if total > 0.0 {
+        for count in &mut counts {
+            *count /= total;
+        }
+    }
+
+    counts
+}
+
+// the average score of a round where both players pick at random with these probabilities
+pub fn expected_score(game: &Game, scoring: &Scoring, mine: &[f64], theirs: &[f64]) -> f64 {
+    game.shapes()
+        .flat_map(|my_shape| {
+            game.shapes()
+                .map(move |their_shape| (my_shape, their_shape))
+        })
+        .map(|(my_shape, their_shape)| {
+            mine[my_shape.0]
+                * theirs[their_shape.0]
+                * scoring.score(game, my_shape, their_shape) as f64
+        })
+        .sum()
+}
+
+// how a guide does under every reading of its second column, for deciding whether to trust it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    pub game: Game,
+    pub rounds: usize,
+    // the total score of every mapping, best first
+    pub mappings: Vec<(Mapping, i32)>,
+    pub opponent: Vec<f64>,
+    // the shapes the guide plays if X, Y and Z are rock, paper and scissors
+    pub guide: Vec<f64>,
+    // per round, for the guide's mix against the opponent's
+    pub expected: f64,
+    // per round, for the best single shape against the opponent's mix
+    pub best_response: (Shape, f64),
+}
+
+pub fn analyse(guide: &[Round]) -> Analysis {
+    let game = Game::rock_paper_scissors();
+    let scoring = Scoring::standard(&game);
+
+    let mut scores = mappings(&game)
+        .into_iter()
+        .map(|mapping| (mapping, play_shapes(&game, &scoring, guide, mapping)))
+        .collect::<Vec<_>>();
+    // stable, so ties keep the order of `mappings`
+    scores.sort_by_key(|&(_, score)| -score);
+
+    let opponent = distribution(&game, guide.iter().map(|round| round.opponent));
+    let mine = distribution(&game, guide.iter().map(|round| Shape(round.column.index())));
+
+    let best_response = game
+        .shapes()
+        .map(|shape| {
+            let mut pure = vec![0.0; game.len()];
+            pure[shape.0] = 1.0;
+
+            (shape, expected_score(&game, &scoring, &pure, &opponent))
+        })
+        .fold(None, |best: Option<(Shape, f64)>, candidate| match best {
+            Some(best) if best.1 >= candidate.1 => Some(best),
+            _ => Some(candidate),
+        })
+        .unwrap();
+
+    Analysis {
+        rounds: guide.len(),
+        mappings: scores,
+        expected: expected_score(&game, &scoring, &mine, &opponent),
+        opponent,
+        guide: mine,
+        best_response,
+        game,
+    }
+}
+
+impl Analysis {
+    pub fn best(&self) -> (Mapping, i32) {
+        self.mappings[0]
+    }
+
+    fn percentages(&self, fractions: &[f64]) -> String {
+        self.game
+            .shapes()
+            .map(|shape| {
+                format!(
+                    "{} {:.1}%",
+                    self.game.name(shape),
+                    fractions[shape.0] * 100.0
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "rounds    {}", self.rounds)?;
+
+        for (mapping, score) in &self.mappings {
+            let mapping = [Column::X, Column::Y, Column::Z]
+                .iter()
+                .zip(mapping)
+                .map(|(column, &shape)| format!("{:?}={}", column, self.game.name(shape)))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            writeln!(f, "{:<32} {}", mapping, score)?;
+        }
+
+        writeln!(f, "opponent  {}", self.percentages(&self.opponent))?;
+        writeln!(f, "guide     {}", self.percentages(&self.guide))?;
+        writeln!(f, "expected  {:.2} per round", self.expected)?;
+        writeln!(
+            f,
+            "always {}  {:.2} per round",
+            self.game.name(self.best_response.0),
+            self.best_response.1
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analysis::{analyse, distribution, expected_score, mappings},
+        parse_strategy_guide, Game, Scoring, PAPER, ROCK, SCISSORS,
+    };
+
+    const STRATEGY_GUIDE: &str = include_str!("../../fixtures/day02/example.txt");
+
+    #[test]
+    fn test_mappings() {
+        let game = Game::rock_paper_scissors();
+        let mappings = mappings(&game);
+
+        assert_eq!(mappings.len(), 6);
+        assert_eq!(mappings[0], [ROCK, PAPER, SCISSORS]);
+        assert!(mappings.contains(&[SCISSORS, PAPER, ROCK]));
+    }
+
+    #[test]
+    fn test_expected_score() {
+        let game = Game::rock_paper_scissors();
+        let scoring = Scoring::standard(&game);
+        let uniform = [1.0 / 3.0; 3];
+
+        // every outcome equally likely, and the shapes are worth 2 on average
+        assert!((expected_score(&game, &scoring, &uniform, &uniform) - 5.0).abs() < 1e-9);
+        assert_eq!(
+            expected_score(&game, &scoring, &[0.0, 1.0, 0.0], &[1.0, 0.0, 0.0]),
+            8.0
+        );
+        assert_eq!(
+            distribution(&game, [ROCK, ROCK, PAPER, ROCK]),
+            vec![0.75, 0.25, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_analyse() {
+        let analysis = analyse(&parse_strategy_guide(STRATEGY_GUIDE).unwrap());
+
+        assert_eq!(analysis.rounds, 3);
+        assert_eq!(analysis.mappings.len(), 6);
+        // reading the guide backwards does better than what it meant
+        assert_eq!(analysis.best(), ([SCISSORS, PAPER, ROCK], 24));
+        assert!(analysis.mappings.contains(&([ROCK, PAPER, SCISSORS], 15)));
+        assert_eq!(analysis.opponent, vec![1.0 / 3.0; 3]);
+        // scissors is worth the most, and every shape wins as often as it loses
+        assert_eq!(analysis.best_response, (SCISSORS, 6.0));
+        assert!((analysis.expected - 5.0).abs() < 1e-9);
+
+        let report = analysis.to_string();
+        assert!(report.starts_with("rounds    3\nX=scissors Y=paper Z=rock"));
+        assert!(report.contains("opponent  rock 33.3%, paper 33.3%, scissors 33.3%\n"));
+    }
+}
@@ -0,0 +1,451 @@
+use std::str::FromStr;
+
+use common::{
+    parse::{parse_field, parse_lines},
+    Answer, ParseError, Solution,
+};
+
+pub mod analysis;
+
+// an index into the shapes of a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shape(pub usize);
+
+pub const ROCK: Shape = Shape(0);
+pub const PAPER: Shape = Shape(1);
+pub const SCISSORS: Shape = Shape(2);
+
+// the opponent's column, A, B and C are rock, paper and scissors
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(textual_representation: &str) -> Result<Self, Self::Err> {
+        match textual_representation {
+            "A" => Ok(ROCK),
+            "B" => Ok(PAPER),
+            "C" => Ok(SCISSORS),
+            _ => Err(ParseError::new(
+                textual_representation,
+                textual_representation,
+                "invalid choice",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+// which shape beats which, every pair of different shapes has exactly one winner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    // beats[a][b] when a beats b
+    beats: Vec<Vec<bool>>,
+}
+
+impl Game {
+    // every shape beats the half of the others that come just before it, wrapping around.
+    // panics on an even number of shapes, those can't be shared out fairly, and on a single
+    // shape that can't win or lose
+    pub fn cyclic<S: ToString>(names: &[S]) -> Self {
+        let count = names.len();
+        assert!(
+            count % 2 == 1 && count >= 3,
+            "a cyclic game needs an odd number of shapes, at least 3"
+        );
+
+        Game {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            beats: (0..count)
+                .map(|a| {
+                    (0..count)
+                        .map(|b| (1..=count / 2).contains(&((a + count - b) % count)))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    // a game from (winner, loser) pairs. the guide can ask for any outcome against any of the
+    // first three shapes, so there have to be three and each has to win and lose somewhere
+    pub fn from_rules<S: ToString>(names: &[S], rules: &[(&str, &str)]) -> Result<Self, String> {
+        let names = names
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+
+        let find = |name: &str| {
+            names
+                .iter()
+                .position(|candidate| candidate == name)
+                .ok_or_else(|| format!("unknown shape {}", name))
+        };
+
+        for &(winner, loser) in rules {
+            beats[find(winner)?][find(loser)?] = true;
+        }
+
+        for a in 0..names.len() {
+            for b in a..names.len() {
+                let decided = beats[a][b] as u32 + beats[b][a] as u32;
+
+                if decided != (a != b) as u32 {
+                    return Err(format!(
+                        "{} against {} needs exactly one winner",
+                        names[a], names[b]
+                    ));
+                }
+            }
+        }
+
+        if names.len() < 3 {
+            return Err(format!("expected at least 3 shapes, found {}", names.len()));
+        }
+
+        for (a, name) in names.iter().enumerate() {
+            if !beats[a].contains(&true) {
+                return Err(format!("{} never wins", name));
+            }
+
+            if !beats.iter().any(|row| row[a]) {
+                return Err(format!("{} never loses", name));
+            }
+        }
+
+        Ok(Game { names, beats })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Game::cyclic(&["rock", "paper", "scissors"])
+    }
+
+    // in this order every shape beats the two before it
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Game::cyclic(&["rock", "spock", "paper", "lizard", "scissors"])
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn outcome(&self, mine: Shape, theirs: Shape) -> Outcome {
+        if self.beats[mine.0][theirs.0] {
+            Outcome::Win
+        } else if self.beats[theirs.0][mine.0] {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // the shapes that get `outcome` against `theirs`
+    pub fn responses(&self, theirs: Shape, outcome: Outcome) -> impl Iterator<Item = Shape> + '_ {
+        self.shapes()
+            .filter(move |&mine| self.outcome(mine, theirs) == outcome)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    // by shape
+    pub shapes: Vec<i32>,
+    pub loss: i32,
+    pub draw: i32,
+    pub win: i32,
+}
+
+impl Scoring {
+    // 1 for the first shape, 2 for the second and so on, and 0, 3 or 6 for the outcome
+    pub fn standard(game: &Game) -> Self {
+        Scoring {
+            shapes: (1..=game.len() as i32).collect(),
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+
+    pub fn outcome(&self, outcome: Outcome) -> i32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+
+    pub fn score(&self, game: &Game, mine: Shape, theirs: Shape) -> i32 {
+        self.shapes[mine.0] + self.outcome(game.outcome(mine, theirs))
+    }
+}
+
+// what the second column means depends on the part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+impl FromStr for Column {
+    type Err = ParseError;
+
+    fn from_str(textual_representation: &str) -> Result<Self, Self::Err> {
+        match textual_representation {
+            "X" => Ok(Column::X),
+            "Y" => Ok(Column::Y),
+            "Z" => Ok(Column::Z),
+            _ => Err(ParseError::new(
+                textual_representation,
+                textual_representation,
+                "invalid column",
+            )),
+        }
+    }
+}
+
+impl Column {
+    pub fn index(self) -> usize {
+        match self {
+            Column::X => 0,
+            Column::Y => 1,
+            Column::Z => 2,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub column: Column,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(round: &str) -> Result<Self, Self::Err> {
+        let (opponent, column) = round
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(round, round, "expected two columns"))?;
+
+        Ok(Round {
+            opponent: parse_field(round, opponent)?,
+            column: parse_field(round, column)?,
+        })
+    }
+}
+
+pub fn parse_strategy_guide(strategy_guide: &str) -> Result<Vec<Round>, ParseError> {
+    parse_lines(strategy_guide, 1)
+}
+
+// the second column is the shape to play, `shapes` being what X, Y and Z mean
+pub fn play_shapes(game: &Game, scoring: &Scoring, guide: &[Round], shapes: [Shape; 3]) -> i32 {
+    guide
+        .iter()
+        .map(|round| scoring.score(game, shapes[round.column.index()], round.opponent))
+        .sum()
+}
+
+// the second column is how the round should end, playing the best scoring shape that does.
+// every game has a shape for every outcome, from_rules makes sure of that
+pub fn play_outcomes(
+    game: &Game,
+    scoring: &Scoring,
+    guide: &[Round],
+    outcomes: [Outcome; 3],
+) -> i32 {
+    guide
+        .iter()
+        .map(|round| {
+            let outcome = outcomes[round.column.index()];
+
+            game.responses(round.opponent, outcome)
+                .map(|mine| scoring.score(game, mine, round.opponent))
+                .max()
+                .unwrap()
+        })
+        .sum()
+}
+
+pub fn part1(strategy_guide: &[Round]) -> i32 {
+    let game = Game::rock_paper_scissors();
+
+    play_shapes(
+        &game,
+        &Scoring::standard(&game),
+        strategy_guide,
+        [ROCK, PAPER, SCISSORS],
+    )
+}
+
+pub fn part2(strategy_guide: &[Round]) -> i32 {
+    let game = Game::rock_paper_scissors();
+
+    play_outcomes(
+        &game,
+        &Scoring::standard(&game),
+        strategy_guide,
+        [Outcome::Loss, Outcome::Draw, Outcome::Win],
+    )
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_strategy_guide(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_strategy_guide, part1, part2, play_outcomes, play_shapes, Game, Outcome, Scoring,
+        Shape, PAPER, ROCK, SCISSORS,
+    };
+
+    const STRATEGY_GUIDE: &str = include_str!("../../fixtures/day02/example.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_strategy_guide(STRATEGY_GUIDE).unwrap()), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_strategy_guide(STRATEGY_GUIDE).unwrap()), 12);
+    }
+
+    #[test]
+    fn test_games() {
+        let game = Game::rock_paper_scissors();
+        assert_eq!(game.outcome(PAPER, ROCK), Outcome::Win);
+        assert_eq!(game.outcome(ROCK, PAPER), Outcome::Loss);
+        assert_eq!(game.outcome(SCISSORS, SCISSORS), Outcome::Draw);
+        assert_eq!(game.outcome(ROCK, SCISSORS), Outcome::Win);
+
+        let names = ["rock", "paper", "scissors"];
+        let rules = [
+            ("paper", "rock"),
+            ("scissors", "paper"),
+            ("rock", "scissors"),
+        ];
+        assert_eq!(Game::from_rules(&names, &rules), Ok(game));
+        assert_eq!(
+            Game::from_rules(&names, &rules[..2]),
+            Err("rock against scissors needs exactly one winner".to_owned())
+        );
+        assert_eq!(
+            Game::from_rules(&names[..2], &rules[..1]),
+            Err("expected at least 3 shapes, found 2".to_owned())
+        );
+
+        // a transitive game, rock beats everything and scissors beats nothing
+        let transitive = [
+            ("rock", "paper"),
+            ("rock", "scissors"),
+            ("paper", "scissors"),
+        ];
+        assert_eq!(
+            Game::from_rules(&names, &transitive),
+            Err("rock never loses".to_owned())
+        );
+
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let shape = |name| game.shape(name).unwrap();
+        assert_eq!(game.outcome(shape("lizard"), shape("spock")), Outcome::Win);
+        assert_eq!(
+            game.outcome(shape("spock"), shape("scissors")),
+            Outcome::Win
+        );
+        assert_eq!(
+            game.outcome(shape("scissors"), shape("rock")),
+            Outcome::Loss
+        );
+        assert_eq!(
+            game.responses(shape("paper"), Outcome::Win)
+                .map(|shape| game.name(shape))
+                .collect::<Vec<_>>(),
+            vec!["lizard", "scissors"]
+        );
+
+        // rps-101, every weapon beats 50 of the others
+        let names = (0..101).collect::<Vec<_>>();
+        let game = Game::cyclic(&names);
+        for theirs in game.shapes() {
+            assert_eq!(game.responses(theirs, Outcome::Win).count(), 50);
+            assert_eq!(game.responses(theirs, Outcome::Loss).count(), 50);
+        }
+    }
+
+    #[test]
+    fn test_scoring() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let guide = parse_strategy_guide(STRATEGY_GUIDE).unwrap();
+
+        // A, B and C are still the first three shapes, rock, spock and paper
+        let scoring = Scoring::standard(&game);
+        assert_eq!(
+            play_shapes(&game, &scoring, &guide, [Shape(0), Shape(1), Shape(2)]),
+            // spock vaporises rock, rock loses to spock, paper draws
+            (2 + 6) + 1 + (3 + 3)
+        );
+
+        let scoring = Scoring {
+            shapes: vec![0; 5],
+            loss: -1,
+            draw: 0,
+            win: 1,
+        };
+        assert_eq!(
+            play_outcomes(
+                &game,
+                &scoring,
+                &guide,
+                [Outcome::Loss, Outcome::Draw, Outcome::Win]
+            ),
+            0 - 1 + 1
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_strategy_guide("A Y\nB Q").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: invalid column 'Q'");
+
+        let error = parse_strategy_guide("D X").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: invalid choice 'D'");
+    }
+}
@@ -0,0 +1,320 @@
+use std::str::FromStr;
+
+use common::{parse::parse_lines, Answer, ParseError, Solution};
+
+fn try_priority(item: char) -> Option<i32> {
+    match item {
+        'a'..='z' => Some(item as i32 - 'a' as i32 + 1),
+        'A'..='Z' => Some(item as i32 - 'A' as i32 + 27),
+        _ => None,
+    }
+}
+
+pub fn priority(item: char) -> i32 {
+    try_priority(item).unwrap_or_else(|| panic!("Invalid item: {}", item))
+}
+
+// the item with `priority`, the inverse of priority
+pub fn item(priority: i32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        27..=52 => (b'A' + (priority - 27) as u8) as char,
+        _ => panic!("Invalid priority: {}", priority),
+    }
+}
+
+// a set of items with one bit per priority, so bit 0 is never set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    // every item from a to Z
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    pub fn insert(&mut self, item: char) {
+        self.0 |= 1 << priority(item);
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        try_priority(item).is_some_and(|priority| self.0 & (1 << priority) != 0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    // the items in order of priority
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        let mut bits = self.0;
+
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+
+            let priority = bits.trailing_zeros();
+            bits &= bits - 1;
+
+            Some(item(priority as i32))
+        })
+    }
+
+    pub fn priorities(self) -> i32 {
+        self.iter().map(priority).sum()
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(items: I) -> Self {
+        let mut set = ItemSet::EMPTY;
+
+        for item in items {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+// the items of `items`, a slice of `line` so errors point into the line
+fn parse_items(line: &str, items: &str) -> Result<ItemSet, ParseError> {
+    let mut set = ItemSet::EMPTY;
+
+    for (offset, item) in items.char_indices() {
+        if try_priority(item).is_none() {
+            let text = &items[offset..offset + item.len_utf8()];
+
+            return Err(ParseError::new(line, text, "invalid item"));
+        }
+
+        set.insert(item);
+    }
+
+    Ok(set)
+}
+
+impl FromStr for ItemSet {
+    type Err = ParseError;
+
+    fn from_str(items: &str) -> Result<Self, Self::Err> {
+        parse_items(items, items)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    // the first and second half of the line
+    pub compartments: [ItemSet; 2],
+}
+
+impl Rucksack {
+    pub fn items(&self) -> ItemSet {
+        self.compartments[0].union(self.compartments[1])
+    }
+
+    // the items that were put in both compartments
+    pub fn misplaced(&self) -> ItemSet {
+        self.compartments[0].intersection(self.compartments[1])
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        // every valid item is ascii so the middle byte splits a valid line in half, anything
+        // else only has to split somewhere so the invalid item can be found
+        let middle = (line.len() / 2..)
+            .find(|&index| line.is_char_boundary(index))
+            .unwrap_or(line.len());
+        let (first, second) = line.split_at(middle);
+
+        Ok(Rucksack {
+            compartments: [parse_items(line, first)?, parse_items(line, second)?],
+        })
+    }
+}
+
+pub fn parse_rucksacks(rucksack_content: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse_lines(rucksack_content, 1)
+}
+
+// the items that every set in a group of `size` has in common, one set per group. the sets
+// have to split into whole groups, the error is on the line of the group that is cut short
+pub fn common_items<I>(sets: I, size: usize) -> Result<Vec<ItemSet>, ParseError>
+where
+    I: IntoIterator<Item = ItemSet>,
+{
+    if size == 0 {
+        return Err(ParseError::new("", "", "groups need at least one rucksack"));
+    }
+
+    let mut groups = Vec::new();
+    let mut common = ItemSet::ALL;
+    let mut count = 0;
+
+    for set in sets {
+        common = common.intersection(set);
+        count += 1;
+
+        if count % size == 0 {
+            groups.push(common);
+            common = ItemSet::ALL;
+        }
+    }
+
+    if count % size != 0 {
+        let message = format!(
+            "incomplete group, expected {} rucksacks but found {}",
+            size,
+            count % size
+        );
+
+        return Err(ParseError::new("", "", &message).on_line(count - count % size + 1));
+    }
+
+    Ok(groups)
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> i32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| rucksack.misplaced().priorities())
+        .sum()
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Result<i32, ParseError> {
+    let badges = common_items(rucksacks.iter().map(Rucksack::items), 3)?;
+
+    Ok(badges.into_iter().map(ItemSet::priorities).sum())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_rucksacks(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    // rucksacks that don't split into groups of three have no answer
+    fn part2(input: &Self::Input) -> Answer {
+        Self::try_part2(input).unwrap_or(Answer::Unsolved)
+    }
+
+    fn try_part2(input: &Self::Input) -> Result<Answer, ParseError> {
+        Ok(part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Answer, Solution};
+
+    use crate::{common_items, item, parse_rucksacks, part1, part2, priority, Day3, ItemSet};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day03/example.txt");
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(16, priority('p'));
+        assert_eq!(38, priority('L'));
+        assert_eq!(42, priority('P'));
+        assert_eq!(22, priority('v'));
+        assert_eq!(20, priority('t'));
+        assert_eq!(19, priority('s'));
+
+        for priority in 1..=52 {
+            assert_eq!(crate::priority(item(priority)), priority);
+        }
+    }
+
+    #[test]
+    fn test_item_set() {
+        let first = "vJrwpWtwJgWr".parse::<ItemSet>().unwrap();
+        let second = "hcsFMMfFFhFp".parse::<ItemSet>().unwrap();
+
+        assert_eq!(first.len(), 8);
+        assert!(first.contains('J') && !first.contains('h') && !first.contains('!'));
+        assert_eq!(first.intersection(second).iter().collect::<String>(), "p");
+        assert_eq!(
+            first.union(second).iter().collect::<String>(),
+            "cfghprstvwFJMW"
+        );
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert_eq!(ItemSet::ALL.iter().last(), Some('Z'));
+        assert!(ItemSet::EMPTY.is_empty());
+
+        let error = "ab1".parse::<ItemSet>().unwrap_err();
+        assert_eq!(error.to_string(), "invalid item '1'");
+
+        let error = parse_rucksacks("abab\naxé").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: invalid item 'é'");
+
+        let error = parse_rucksacks("éa").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: invalid item 'é'");
+
+        let error = parse_rucksacks("abcd1f").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 5: invalid item '1'");
+    }
+
+    #[test]
+    fn test_common_items() {
+        let sets = ["abc", "bcd", "cde", "xy", "yz", "y"].map(|items| items.parse().unwrap());
+
+        let common = common_items(sets, 3).unwrap();
+        assert_eq!(common, vec!["c".parse().unwrap(), "y".parse().unwrap()]);
+
+        assert_eq!(common_items(sets, 2).unwrap().len(), 3);
+
+        let error = common_items(sets, 0).unwrap_err();
+        assert_eq!(error.to_string(), "groups need at least one rucksack");
+
+        let error = common_items(sets, 4).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 1: incomplete group, expected 4 rucksacks but found 2"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_rucksacks(TEST_INPUT).unwrap()), 157);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_rucksacks(TEST_INPUT).unwrap()), Ok(70));
+
+        let rucksacks = parse_rucksacks(TEST_INPUT).unwrap();
+        assert!(part2(&rucksacks[..4]).is_err());
+
+        // part 1 doesn't need whole groups
+        let input = TEST_INPUT.lines().take(4).collect::<Vec<_>>().join("\n");
+        let rucksacks = Day3::parse(&input).unwrap();
+        assert_eq!(Day3::part1(&rucksacks), Answer::Integer(16 + 38 + 42 + 22));
+        assert_eq!(Day3::part2(&rucksacks), Answer::Unsolved);
+        assert_eq!(
+            Day3::try_part2(&rucksacks).unwrap_err().to_string(),
+            "line 4, column 1: incomplete group, expected 3 rucksacks but found 1"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use std::{ops::Range, str::FromStr};
+
+use common::{
+    parse::{parse_lines, parse_number},
+    Answer, ParseError, Solution,
+};
+
+// `line` is only used to place errors
+fn parse_assignment_string(line: &str, assignment_string: &str) -> Result<Range<i32>, ParseError> {
+    let (start, end) = assignment_string
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(line, assignment_string, "expected a range like 2-4"))?;
+
+    Ok(parse_number(line, start)?..parse_number(line, end)?)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AssignmentPair {
+    pub first: Range<i32>,
+    pub second: Range<i32>,
+}
+
+impl FromStr for AssignmentPair {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (first, second) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(line, line, "expected two comma separated ranges"))?;
+
+        Ok(AssignmentPair {
+            first: parse_assignment_string(line, first)?,
+            second: parse_assignment_string(line, second)?,
+        })
+    }
+}
+
+pub fn parse_assignments(assignments_string: &str) -> Result<Vec<AssignmentPair>, ParseError> {
+    parse_lines(assignments_string, 1)
+}
+
+fn is_range_subset_of_other_range(range: &Range<i32>, other_range: &Range<i32>) -> bool {
+    range.start >= other_range.start && range.end <= other_range.end
+}
+
+pub fn part1(assignments: &[AssignmentPair]) -> i32 {
+    assignments
+        .iter()
+        .map(|AssignmentPair { first, second }| {
+            is_range_subset_of_other_range(first, second)
+                || is_range_subset_of_other_range(second, first)
+        })
+        .filter(|x| x == &true)
+        .count()
+        .try_into()
+        .unwrap()
+}
+
+pub fn part2(assignments: &[AssignmentPair]) -> i32 {
+    assignments
+        .iter()
+        .map(|AssignmentPair { first, second }| {
+            first.end >= second.start && second.end >= first.start
+        })
+        .filter(|x| x == &true)
+        .count()
+        .try_into()
+        .unwrap()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<AssignmentPair>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_assignments(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_assignments, part1, part2, AssignmentPair};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day04/example.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_assignments(TEST_INPUT).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_assignments(TEST_INPUT).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_parse_assignments() {
+        assert_eq!(
+            parse_assignments("2-4,6-8"),
+            Ok(vec![AssignmentPair {
+                first: 2..4,
+                second: 6..8
+            }])
+        );
+
+        let error = parse_assignments("2-4,6-8\n2-3,4_5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a range like 2-4 '4_5'"
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    parse::{parse_lines, parse_number},
+    Answer, ParseError, Solution,
+};
+
+pub type Stacks = HashMap<i32, Vec<char>>;
+
+// every stack from 1 to `number_of_stacks` gets an entry, lines can stop early when their
+// trailing spaces have been trimmed
+pub fn stacks_from_text(
+    textual_representation: &str,
+    number_of_stacks: i32,
+) -> Result<Stacks, ParseError> {
+    let lines = textual_representation.lines().collect::<Vec<_>>();
+
+    // the last line only labels the stacks
+    let Some((_, crate_lines)) = lines.split_last() else {
+        return Err(ParseError::new("", "", "expected a drawing of the stacks").on_line(1));
+    };
+
+    let item_indices = (1..(3 * number_of_stacks + number_of_stacks - 1))
+        .step_by(4)
+        .collect::<Vec<_>>();
+
+    let stack_lines = crate_lines
+        .iter()
+        .map(|line| {
+            item_indices
+                .iter()
+                .map(|index| line.chars().nth(*index as usize).unwrap_or(' '))
+                .collect::<Vec<_>>()
+        })
+        .rev()
+        .collect::<Vec<_>>();
+
+    let mut stacks = (1..=number_of_stacks)
+        .map(|stack_number| (stack_number, Vec::new()))
+        .collect::<Stacks>();
+
+    for stack_line in stack_lines {
+        for (index, character) in stack_line.iter().enumerate() {
+            match character {
+                ' ' => {}
+                _ => {
+                    let stack = stacks.entry((index + 1) as i32).or_default();
+                    stack.push(*character);
+                }
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+// the last line of the stack drawing labels every stack
+pub fn count_stacks(input_string: &str) -> i32 {
+    let stacks_string = input_string.split("\n\n").next().unwrap();
+
+    stacks_string
+        .lines()
+        .last()
+        .unwrap_or_default()
+        .split_whitespace()
+        .count() as i32
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub count: i32,
+
+    pub from: i32,
+    pub to: i32,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let parts = text.split_whitespace().collect::<Vec<_>>();
+
+        let ["move", count, "from", from, "to", to] = parts[..] else {
+            return Err(ParseError::new(
+                text,
+                text,
+                "expected 'move <count> from <stack> to <stack>'",
+            ));
+        };
+
+        Ok(Instruction {
+            count: parse_number(text, count)?,
+            from: parse_number(text, from)?,
+            to: parse_number(text, to)?,
+        })
+    }
+}
+
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub number_of_stacks: i32,
+    pub instructions: Vec<Instruction>,
+}
+
+pub fn parse_procedure(input_string: &str, number_of_stacks: i32) -> Result<Procedure, ParseError> {
+    let (stacks_string, instructions_string) =
+        input_string.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(input_string, "", "expected a blank line after the stacks").on_line(1)
+        })?;
+
+    // the instructions start after the stacks and the blank line
+    let first_instruction_line = stacks_string.lines().count() + 2;
+
+    let stacks = stacks_from_text(stacks_string, number_of_stacks)?;
+    let instructions = parse_lines::<Instruction>(instructions_string, first_instruction_line)?;
+
+    // both cranes move the same number of crates, so the heights can be checked up front
+    let mut heights = (1..=number_of_stacks)
+        .map(|stack_number| (stack_number, stacks[&stack_number].len() as i32))
+        .collect::<HashMap<_, _>>();
+
+    for ((index, line), instruction) in instructions_string.lines().enumerate().zip(&instructions) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let error = |field: usize, message: &str| {
+            ParseError::new(line, fields[field], message).on_line(first_instruction_line + index)
+        };
+
+        for (field, stack_number) in [(3, instruction.from), (5, instruction.to)] {
+            if !heights.contains_key(&stack_number) {
+                return Err(error(field, "unknown stack"));
+            }
+        }
+
+        let from = heights[&instruction.from];
+
+        if !(0..=from).contains(&instruction.count) {
+            let message = format!(
+                "stack {} holds {} crates, can't move",
+                instruction.from, from
+            );
+
+            return Err(error(1, &message));
+        }
+
+        heights.insert(instruction.from, from - instruction.count);
+        *heights.entry(instruction.to).or_default() += instruction.count;
+    }
+
+    Ok(Procedure {
+        stacks,
+        number_of_stacks,
+        instructions,
+    })
+}
+
+fn top_of_stacks(stacks: &Stacks, number_of_stacks: i32) -> String {
+    (1..=number_of_stacks)
+        .map(|stack_number| {
+            stacks
+                .get(&stack_number)
+                .and_then(|stack| stack.last())
+                .unwrap_or(&' ')
+        })
+        .collect::<String>()
+}
+
+// moves the crates all at once, or one at a time which reverses them
+fn move_crates(stacks: &mut Stacks, instruction: &Instruction, one_at_a_time: bool) {
+    let from = stacks.entry(instruction.from).or_default();
+    let split = from.len().saturating_sub(instruction.count.max(0) as usize);
+    let mut moved = from.split_off(split);
+
+    if one_at_a_time {
+        moved.reverse();
+    }
+
+    stacks.entry(instruction.to).or_default().extend(moved);
+}
+
+pub fn part1(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+
+    for instruction in &procedure.instructions {
+        move_crates(&mut stacks, instruction, true);
+    }
+
+    top_of_stacks(&stacks, procedure.number_of_stacks)
+}
+
+pub fn part2(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+
+    for instruction in &procedure.instructions {
+        move_crates(&mut stacks, instruction, false);
+    }
+
+    top_of_stacks(&stacks, procedure.number_of_stacks)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_procedure(input_string, count_stacks(input_string))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{count_stacks, parse_procedure, part1, part2, Instruction};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day05/example.txt");
+
+    #[test]
+    fn test_count_stacks() {
+        assert_eq!(count_stacks(TEST_INPUT), 3);
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(
+            "move 3 from 1 to 3".parse(),
+            Ok(Instruction {
+                count: 3,
+                from: 1,
+                to: 3
+            })
+        );
+
+        let error = parse_procedure(&TEST_INPUT.replace("from 2 to 1", "from 2 onto 1"), 3)
+            .err()
+            .unwrap();
+        assert_eq!(error.line, 6);
+        assert_eq!(
+            error.message,
+            "expected 'move <count> from <stack> to <stack>'"
+        );
+    }
+
+    #[test]
+    fn test_parse_procedure() {
+        // trailing spaces trimmed from the drawing
+        let trimmed = TEST_INPUT
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(part1(&parse_procedure(&trimmed, 3).unwrap()), "CMZ");
+
+        let error = parse_procedure("\n\nmove 1 from 1 to 2", 0).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a drawing of the stacks"
+        );
+
+        let error = parse_procedure(&TEST_INPUT.replace("from 2 to 1", "from 2 to 4"), 3)
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "line 6, column 18: unknown stack '4'");
+
+        let error = parse_procedure(&TEST_INPUT.replace("move 3", "move 4"), 3)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 6: stack 1 holds 3 crates, can't move '4'"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            part1(&parse_procedure(TEST_INPUT, 3).unwrap()),
+            "CMZ".to_owned()
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&parse_procedure(TEST_INPUT, 3).unwrap()),
+            "MCD".to_owned()
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use common::{Answer, ParseError, Solution};
+
+pub fn solve(input_string: &str, message_size: usize) -> usize {
+    let mut buffer: HashSet<char> = HashSet::from_iter(input_string[0..message_size].chars());
+    let mut index = message_size;
+
+    while buffer.len() != message_size {
+        index += 1;
+        buffer.clear();
+        buffer.extend(input_string[index - message_size..index].chars());
+    }
+
+    index
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        Ok(input_string.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, 4).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, 14).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solve;
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day06/example.txt");
+    const TEST_INPUT_2: &str = include_str!("../../fixtures/day06/example2.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(solve(TEST_INPUT, 4), 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(solve(TEST_INPUT_2, 14), 29);
+    }
+}
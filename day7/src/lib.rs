@@ -0,0 +1,239 @@
+use std::str::FromStr;
+
+use common::{parse::parse_number, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    files: Vec<(String, i32)>,
+
+    sub_directories: Vec<Directory>,
+}
+
+impl Directory {
+    fn new(name: &str) -> Directory {
+        Directory {
+            name: name.to_owned(),
+            files: Vec::new(),
+            sub_directories: Vec::new(),
+        }
+    }
+
+    // the directory reached by following `path`, indices into sub_directories from here
+    fn at(&mut self, path: &[usize]) -> &mut Directory {
+        path.iter().fold(self, |directory, &index| {
+            &mut directory.sub_directories[index]
+        })
+    }
+
+    fn size(&self) -> i32 {
+        self.files.iter().map(|(_, size)| size).sum::<i32>()
+            + self
+                .sub_directories
+                .iter()
+                .map(|dir| dir.size())
+                .sum::<i32>()
+    }
+
+    fn size_of_directories_under_or_eq_size(&self, max_size: i32) -> i32 {
+        let mut total = 0;
+
+        if self.size() <= max_size {
+            total += self.size();
+        }
+
+        for sub_dir in &self.sub_directories {
+            total += sub_dir.size_of_directories_under_or_eq_size(max_size);
+        }
+
+        total
+    }
+
+    fn directories_larger_or_eq_than(&self, size: i32) -> Vec<i32> {
+        let mut candidates: Vec<i32> = vec![];
+
+        if self.size() >= size {
+            candidates.push(self.size());
+        }
+
+        for sub_dir in &self.sub_directories {
+            candidates.extend(sub_dir.directories_larger_or_eq_than(size));
+        }
+
+        candidates
+    }
+}
+
+#[derive(Debug)]
+enum Child {
+    Directory(String),
+    File(String, i32),
+}
+
+impl FromStr for Child {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (head, tail) = str
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(str, str, "expected a size or 'dir' and a name"))?;
+
+        match head {
+            "dir" => Ok(Child::Directory(tail.to_string())),
+            _ => Ok(Child::File(tail.to_string(), parse_number(str, head)?)),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operation<'a> {
+    // the directory is a slice of the line so errors can point at it
+    Cd(&'a str),
+    Ls(Vec<Child>),
+}
+
+// every operation with the number and text of the line it starts on
+fn parse_operations(input_string: &str) -> Result<Vec<(usize, &str, Operation<'_>)>, ParseError> {
+    let mut operations = vec![];
+
+    for (index, line) in input_string.lines().enumerate() {
+        let line_number = index + 1;
+
+        if let Some(command) = line.strip_prefix("$ ") {
+            if let Some(path) = command.strip_prefix("cd ") {
+                operations.push((line_number, line, Operation::Cd(path)));
+            } else if command == "ls" {
+                operations.push((line_number, line, Operation::Ls(vec![])));
+            } else {
+                return Err(ParseError::new(line, command, "unknown command").on_line(line_number));
+            }
+
+            continue;
+        }
+
+        let child = line
+            .parse()
+            .map_err(|error: ParseError| error.on_line(line_number))?;
+
+        // everything that isn't a command is output from the latest ls
+        let Some((_, _, Operation::Ls(children))) = operations.last_mut() else {
+            return Err(ParseError::new(line, line, "output without ls").on_line(line_number));
+        };
+
+        children.push(child);
+    }
+
+    Ok(operations)
+}
+
+pub fn setup(input_string: &str) -> Result<Directory, ParseError> {
+    let mut tree = Directory::new("/");
+    // the working directory, empty at the root
+    let mut path: Vec<usize> = Vec::new();
+
+    for (line_number, line, operation) in parse_operations(input_string)? {
+        match operation {
+            Operation::Cd("/") => path.clear(),
+            // the root is its own parent
+            Operation::Cd("..") => {
+                path.pop();
+            }
+            Operation::Cd(name) => {
+                let index = tree
+                    .at(&path)
+                    .sub_directories
+                    .iter()
+                    .position(|directory| directory.name == name)
+                    .ok_or_else(|| {
+                        ParseError::new(line, name, "unknown directory").on_line(line_number)
+                    })?;
+
+                path.push(index);
+            }
+            Operation::Ls(children) => {
+                let directory = tree.at(&path);
+
+                for child in children {
+                    match child {
+                        Child::Directory(name) => {
+                            directory.sub_directories.push(Directory::new(&name))
+                        }
+                        Child::File(name, size) => directory.files.push((name, size)),
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+pub fn part1(dir: &Directory) -> i32 {
+    dir.size_of_directories_under_or_eq_size(100_000)
+}
+
+pub fn part2(dir: &Directory) -> i32 {
+    let mut candidates = dir.directories_larger_or_eq_than(30000000 - (70000000 - dir.size()));
+
+    candidates.sort();
+
+    *candidates.first().unwrap()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Directory;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        setup(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{part1, part2, setup};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day07/example.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&setup(TEST_INPUT).unwrap()), 95437);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&setup(TEST_INPUT).unwrap()), 24933642);
+    }
+
+    #[test]
+    fn test_setup_errors() {
+        let error = setup("$ ls\ndir a\n12x b.txt").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: invalid number '12x'");
+
+        let error = setup("$ ls\n$ rm a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unknown command 'rm a'"
+        );
+
+        let error = setup("$ cd /\n$ ls\ndir a\n$ cd a\n$ cd b").unwrap_err();
+        assert_eq!(error.to_string(), "line 5, column 6: unknown directory 'b'");
+    }
+
+    #[test]
+    fn test_cd_root() {
+        let tree = setup("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n10 b\n$ cd /\n$ ls\n5 c").unwrap();
+
+        assert_eq!(tree.size(), 15);
+        assert_eq!(tree.sub_directories[0].size(), 10);
+    }
+}
@@ -0,0 +1,149 @@
+use common::{
+    grid::{Grid, NEIGHBOURS4},
+    Answer, ParseError, Solution,
+};
+
+fn line_one_direction(line: &[char]) -> Vec<bool> {
+    let numbers = line.iter().map(|c| c.to_digit(10).unwrap() as i32);
+
+    let mut max: i32 = -1;
+
+    let mut result: Vec<bool> = vec![];
+
+    for number in numbers {
+        result.push(number > max);
+
+        if number > max {
+            max = number;
+        }
+    }
+
+    result
+}
+
+pub fn line_both_directions(line: &[char]) -> Vec<bool> {
+    let west_to_east = line_one_direction(line);
+
+    let mut reversed = line.to_vec();
+    reversed.reverse();
+
+    let mut east_to_west = line_one_direction(&reversed);
+    east_to_west.reverse();
+
+    west_to_east
+        .into_iter()
+        .zip(east_to_west)
+        .map(|(a, b)| a | b)
+        .collect()
+}
+
+pub fn parse_trees(input_string: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input_string, |c| c.is_ascii_digit().then_some(c))
+}
+
+pub fn part1(trees: &Grid<char>) -> i32 {
+    let mut visible = Grid::new(trees.width(), trees.height(), false);
+
+    for (y, row) in trees.rows().enumerate() {
+        for (x, seen) in line_both_directions(row).into_iter().enumerate() {
+            visible[(x, y)] |= seen;
+        }
+    }
+
+    // the rows of the transposed view are the columns
+    for (x, column) in trees.transposed().rows().enumerate() {
+        let column = column.copied().collect::<Vec<_>>();
+
+        for (y, seen) in line_both_directions(&column).into_iter().enumerate() {
+            visible[(x, y)] |= seen;
+        }
+    }
+
+    visible.iter().filter(|(_, &seen)| seen).count() as i32
+}
+
+// trees seen before one that is at least as high, which is counted as well
+fn viewing_distance(height: char, line: impl Iterator<Item = char>) -> u32 {
+    let mut score = 0;
+
+    for item in line {
+        score += 1;
+
+        if item >= height {
+            break;
+        }
+    }
+
+    score
+}
+
+pub fn part2(trees: &Grid<char>) -> i32 {
+    trees
+        .iter()
+        .map(|(position, &height)| {
+            NEIGHBOURS4
+                .iter()
+                .map(|&direction| {
+                    viewing_distance(
+                        height,
+                        trees.ray(position, direction).map(|point| trees[point]),
+                    )
+                })
+                .product::<u32>()
+        })
+        .max()
+        .unwrap_or(0) as i32
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<char>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_trees(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{line_both_directions, parse_trees, part1, part2};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day08/example.txt");
+
+    #[test]
+    fn test_line_both_dir() {
+        assert_eq!(
+            line_both_directions(&"123454321".chars().collect::<Vec<_>>()),
+            vec![true, true, true, true, true, true, true, true, true]
+        );
+
+        assert_eq!(
+            line_both_directions(&"30373".chars().collect::<Vec<_>>()),
+            vec![true, false, false, true, true]
+        );
+
+        assert_eq!(
+            line_both_directions(&"25512".chars().collect::<Vec<_>>()),
+            vec![true, true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_trees(TEST_INPUT).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_trees(TEST_INPUT).unwrap()), 8);
+    }
+}
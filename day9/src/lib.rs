@@ -0,0 +1,232 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{
+    parse::{parse_lines, parse_number},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    pub fn execute_move(&mut self, next_move: Move) {
+        match next_move {
+            Move::Up(distance) => self.y += distance,
+            Move::Down(distance) => self.y -= distance,
+            Move::Left(distance) => self.x -= distance,
+            Move::Right(distance) => self.x += distance,
+        }
+    }
+
+    pub fn should_move(&self, other: Position) -> bool {
+        (self.x - other.x).abs() > 1 || (self.y - other.y).abs() > 1
+    }
+
+    pub fn move_towards(&mut self, target: &Position) {
+        if self.x != target.x && self.y != target.y {
+            // move diagonally towards the target
+            if self.x < target.x {
+                self.x += 1;
+            } else {
+                self.x -= 1;
+            }
+
+            if self.y < target.y {
+                self.y += 1;
+            } else {
+                self.y -= 1;
+            }
+
+            return;
+        }
+
+        if self.x < target.x {
+            self.x += 1;
+        } else if self.x > target.x {
+            self.x -= 1;
+        } else if self.y < target.y {
+            self.y += 1;
+        } else if self.y > target.y {
+            self.y -= 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Up(i32),
+    Down(i32),
+    Left(i32),
+    Right(i32),
+}
+
+impl Move {
+    fn get_distance(&self) -> i32 {
+        match self {
+            Move::Up(distance) => *distance,
+            Move::Down(distance) => *distance,
+            Move::Left(distance) => *distance,
+            Move::Right(distance) => *distance,
+        }
+    }
+
+    fn single_step(&self) -> Self {
+        match self {
+            Move::Up(_) => Move::Up(1),
+            Move::Down(_) => Move::Down(1),
+            Move::Left(_) => Move::Left(1),
+            Move::Right(_) => Move::Right(1),
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (direction, distance) = input
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(input, input, "expected a direction and a distance"))?;
+
+        let distance = parse_number(input, distance)?;
+
+        match direction {
+            "R" => Ok(Move::Right(distance)),
+            "L" => Ok(Move::Left(distance)),
+            "U" => Ok(Move::Up(distance)),
+            "D" => Ok(Move::Down(distance)),
+            _ => Err(ParseError::new(input, direction, "unknown direction")),
+        }
+    }
+}
+
+pub fn parse_moves(input_string: &str) -> Result<Vec<Move>, ParseError> {
+    parse_lines(input_string, 1)
+}
+
+pub fn part1(moves: &[Move]) -> i32 {
+    let mut head_position = Position { x: 0, y: 0 };
+    let mut tail_position = Position { x: 0, y: 0 };
+
+    let mut visited = HashSet::new();
+
+    visited.insert(tail_position);
+
+    for &next_move in moves {
+        head_position.execute_move(next_move);
+
+        loop {
+            if !tail_position.should_move(head_position) {
+                break;
+            }
+
+            tail_position.move_towards(&head_position);
+
+            visited.insert(tail_position);
+        }
+    }
+
+    visited.len() as i32
+}
+
+pub fn part2(moves: &[Move]) -> i32 {
+    let mut head_position = Position { x: 0, y: 0 };
+    let mut tails = (0..9).map(|_| Position { x: 0, y: 0 }).collect::<Vec<_>>();
+
+    let mut visited: HashSet<Position> = HashSet::new();
+
+    visited.insert(Position { x: 0, y: 0 });
+
+    for &next_move in moves {
+        let distance = next_move.get_distance();
+
+        for _ in 0..distance {
+            head_position.execute_move(next_move.single_step());
+
+            for index in 0..9 {
+                let tail = tails.get(index).unwrap();
+
+                let target = *if index == 0 {
+                    &head_position
+                } else {
+                    &tails[index - 1]
+                };
+
+                let should_move = tail.should_move(target);
+
+                if !should_move {
+                    break;
+                }
+
+                tails.get_mut(index).unwrap().move_towards(&target);
+
+                if index == 8 {
+                    visited.insert(tails[index]);
+                }
+            }
+        }
+    }
+
+    visited.len() as i32
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        parse_moves(input_string)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use crate::{parse_moves, part1, part2, Move};
+
+    const TEST_INPUT: &str = include_str!("../../fixtures/day09/example.txt");
+
+    const TEST_INPUT_2: &str = include_str!("../../fixtures/day09/example2.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_moves(TEST_INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_moves(TEST_INPUT).unwrap()), 1);
+        assert_eq!(part2(&parse_moves(TEST_INPUT_2).unwrap()), 36);
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        assert_eq!(
+            parse_moves("R 4\nU 12"),
+            Ok(vec![Move::Right(4), Move::Up(12)])
+        );
+
+        assert_eq!(
+            parse_moves("R 4\nQ 2").unwrap_err(),
+            ParseError {
+                line: 2,
+                column: 1,
+                text: "Q".to_owned(),
+                message: "unknown direction".to_owned(),
+            }
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use common::{Answer, ParseError, Solution};
+
+pub fn part1(_input_string: &str) -> i32 {
+    0
+}
+
+pub fn part2(_input_string: &str) -> i32 {
+    0
+}
+
+pub struct Template;
+
+impl Solution for Template {
+    type Input = String;
+
+    fn parse(input_string: &str) -> Result<Self::Input, ParseError> {
+        Ok(input_string.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{part1, part2};
+
+    const TEST_INPUT: &str = "";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(TEST_INPUT), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(TEST_INPUT), 0);
+    }
+}
@@ -1,32 +1,10 @@
-pub fn part1(input_string: &str) -> i32 {
-    0
-}
-
-pub fn part2(input_string: &str) -> i32 {
-    0
-}
+use common::input;
+use template::{part1, part2};
 
 fn main() {
-    let input = include_str!("input.txt");
-
-    println!("Part 1: {}", part1(input));
-
-    println!("Part 2: {}", part2(input));
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{part1, part2};
-
-    const TEST_INPUT: &str = "";
+    let input_string = input::from_args(0).unwrap();
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), 0);
-    }
+    println!("Part 1: {}", part1(&input_string));
 
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), 0);
-    }
+    println!("Part 2: {}", part2(&input_string));
 }